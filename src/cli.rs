@@ -1,4 +1,9 @@
-use clap::{Args, Parser, Subcommand};
+use crate::error::Error;
+use crate::pattern::NamePattern;
+use crate::remote_config::ParameterValueType;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use clap::{ArgEnum, Args, Parser, Subcommand};
+use std::str::FromStr;
 
 /// CLI to add, update and delete Firebase Remote Config parameters
 #[derive(Parser)]
@@ -14,19 +19,56 @@ pub enum Command {
     Add(Add),
     /// Updates existing parameter to config
     Update(Update),
-    /// Deletes parameter from config
+    /// Deletes parameters from config
     Delete(Delete),
     /// Move parameter to group
     MoveTo(MoveTo),
     /// Move parameter out the group
     MoveOut(MoveOut),
     /// Show parameters and conditions
-    Show(Project),
+    Show(Show),
     /// Show projects stored in config file
     #[clap(subcommand)]
     Config(Config),
     /// Migrate parameters from one project to others
     Migrate(Migrate),
+    /// Manage parameter groups
+    #[clap(subcommand)]
+    Group(Group),
+    /// Apply operations from YAML or JSON file with a single publish per project
+    Batch(Batch),
+    /// Delete conditions which are not used by any parameter
+    Gc(Gc),
+    /// Switch value of boolean parameter
+    Flag(Flag),
+    /// Roll out parameter value to the percentage of users
+    Rollout(Rollout),
+    /// Set parameter value for the period of time
+    Schedule(Schedule),
+    /// Show changes published by rcm
+    Log(Log),
+    /// Revert the latest changes published by rcm
+    Undo(Undo),
+    /// Download configs of all projects to the backup directory
+    Backup(Backup),
+    /// Publish configs from the backup
+    Restore(Restore),
+    /// Print changes made to remote configs
+    Watch(Watch),
+    /// Generate parameter keys for client apps
+    Codegen(Codegen),
+    /// Generate in-app default values
+    Defaults(Defaults),
+    /// Compare in-app defaults file with remote config and add missing parameters
+    ImportDefaults(ImportDefaults),
+    /// Validate parameter values against their JSON Schemas
+    Lint(Project),
+    /// Edit parameter or the whole template in $EDITOR
+    Edit(Edit),
+    /// Show template statistics and usage of Firebase limits
+    Stats(Project),
+    /// Show parameters using the condition or conditions of the parameter
+    WhereUsed(WhereUsed),
 }
 
 #[derive(Debug, Args)]
@@ -38,6 +80,8 @@ pub struct Add {
     #[clap(short, long)]
     pub description: Option<String>,
     #[clap(flatten)]
+    pub values: ParameterValues,
+    #[clap(flatten)]
     pub project: Project,
 }
 
@@ -47,9 +91,41 @@ pub struct Update {
     #[clap(short, long)]
     pub name: String,
     #[clap(flatten)]
+    pub values: ParameterValues,
+    #[clap(flatten)]
     pub project: Project,
 }
 
+/// Parameter values passed via command line.
+/// If any value is passed, parameter is built without prompting the user.
+#[derive(Debug, Args, Default)]
+pub struct ParameterValues {
+    /// Parameter value type
+    #[clap(short = 't', long = "type", arg_enum)]
+    pub value_type: Option<ParameterValueType>,
+    /// Default value
+    #[clap(long, conflicts_with = "use-in-app-default")]
+    pub default: Option<String>,
+    /// Use in-app default value as default value
+    #[clap(long)]
+    pub use_in_app_default: bool,
+    /// Conditional value in <condition>=<value> format. Can be used multiple times
+    #[clap(long)]
+    pub conditional: Vec<ConditionalValue>,
+    /// Group to put the parameter to
+    #[clap(short, long)]
+    pub group: Option<String>,
+    /// Confirm all changes without asking
+    #[clap(short, long)]
+    pub yes: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConditionalValue {
+    pub condition: String,
+    pub value: String,
+}
+
 #[derive(Debug, Args)]
 pub struct MoveOut {
     /// Parameter to move
@@ -73,13 +149,328 @@ pub struct MoveTo {
 
 #[derive(Debug, Args)]
 pub struct Delete {
-    /// Parameter to delete
+    /// Parameter to delete, glob pattern or regex wrapped in slashes. Can be repeated
+    #[clap(short, long, required_unless_present = "group")]
+    pub name: Vec<NamePattern>,
+    /// Delete all parameters in the group
+    #[clap(short, long)]
+    pub group: Option<String>,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Args)]
+pub struct Batch {
+    /// Path to YAML or JSON file with the list of operations
+    #[clap(parse(from_os_str))]
+    pub path: std::path::PathBuf,
+    /// Publish changes without asking
+    #[clap(short, long)]
+    pub yes: bool,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Args)]
+pub struct Gc {
+    /// Delete all unused conditions without asking
+    #[clap(short, long)]
+    pub yes: bool,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Args)]
+pub struct Flag {
+    #[clap(arg_enum)]
+    pub action: FlagAction,
+    /// Boolean parameter to switch
+    #[clap(short, long)]
+    pub name: String,
+    /// Switch value for the condition instead of default value
+    #[clap(short, long)]
+    pub condition: Option<String>,
+    /// Confirm changes without asking
+    #[clap(short, long)]
+    pub yes: bool,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Clone, Copy, ArgEnum)]
+pub enum FlagAction {
+    On,
+    Off,
+    Toggle,
+}
+
+#[derive(Debug, Args)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct Rollout {
+    #[clap(subcommand)]
+    pub subcommand: Option<RolloutSubcommand>,
+    /// Parameter to roll out
+    #[clap(short, long, required = true)]
+    pub name: Option<String>,
+    /// Value for users in the rollout. Existing value is kept if not passed
+    #[clap(short, long)]
+    pub value: Option<String>,
+    /// Percentage of users in the rollout
+    #[clap(long, required = true)]
+    pub percent: Option<f64>,
+    /// Seed used to randomize users
+    #[clap(long)]
+    pub seed: Option<String>,
+    /// Rollout condition name, <name>_rollout by default
+    #[clap(short, long)]
+    pub condition: Option<String>,
+    /// Confirm changes without asking
+    #[clap(short, long)]
+    pub yes: bool,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Args)]
+pub struct Schedule {
+    /// Parameter to schedule value for
+    #[clap(short, long)]
+    pub name: String,
+    /// Value active in the scheduled period
+    #[clap(short, long)]
+    pub value: String,
+    /// Start of the period in RFC 3339 format or without offset to use device time zone
+    #[clap(long)]
+    pub at: String,
+    /// End of the period, not included
+    #[clap(long)]
+    pub until: Option<String>,
+    /// Time zone for the period without offset, for example America/Los_Angeles
+    #[clap(long)]
+    pub time_zone: Option<String>,
+    /// Schedule condition name, <name>_schedule by default
+    #[clap(short, long)]
+    pub condition: Option<String>,
+    /// Confirm changes without asking
+    #[clap(short, long)]
+    pub yes: bool,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum RolloutSubcommand {
+    /// Widen existing rollout
+    Bump(BumpRollout),
+}
+
+#[derive(Debug, Args)]
+pub struct BumpRollout {
+    /// Parameter in the rollout
     #[clap(short, long)]
     pub name: String,
+    /// New percentage of users
+    #[clap(long)]
+    pub to: f64,
+    /// Rollout condition name, <name>_rollout by default
+    #[clap(short, long)]
+    pub condition: Option<String>,
+    /// Confirm changes without asking
+    #[clap(short, long)]
+    pub yes: bool,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Args)]
+pub struct Log {
+    /// Show changes only for the project
+    #[clap(short, long)]
+    pub project: Option<String>,
+    /// Show changes of parameters matching glob pattern or regex wrapped in slashes
+    #[clap(short, long)]
+    pub name: Option<NamePattern>,
+    /// Show changes published since the time, RFC 3339 or date in UTC
+    #[clap(long, parse(try_from_str = parse_date_time))]
+    pub since: Option<DateTime<Utc>>,
+    /// Show changes published until the time, RFC 3339 or date in UTC including the whole day
+    #[clap(long, parse(try_from_str = parse_end_date_time))]
+    pub until: Option<DateTime<Utc>>,
+    #[clap(short, long, arg_enum, default_value = "table")]
+    pub output: OutputFormat,
+}
+
+#[derive(Debug, Args)]
+pub struct Undo {
+    /// Number of the latest changes to revert
+    #[clap(short, long, default_value = "1")]
+    pub steps: usize,
+    /// Confirm changes without asking
+    #[clap(short, long)]
+    pub yes: bool,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Args)]
+pub struct Backup {
+    /// Directory to create timestamped backups in
+    #[clap(short, long, parse(from_os_str))]
+    pub out: std::path::PathBuf,
+    /// Number of the latest backups to keep in the directory
+    #[clap(long, parse(try_from_str = parse_positive_count))]
+    pub keep: Option<usize>,
+    /// Delete backups older than the number of days
+    #[clap(long)]
+    pub keep_days: Option<u32>,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Args)]
+pub struct Restore {
+    /// Backup directory with manifest.json
+    #[clap(short, long, parse(from_os_str))]
+    pub from: std::path::PathBuf,
+    /// Confirm changes without asking
+    #[clap(short, long)]
+    pub yes: bool,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Args)]
+pub struct Watch {
+    /// Polling interval, for example 30s, 5m or 1h
+    #[clap(short, long, default_value = "60s", parse(try_from_str = parse_duration))]
+    pub interval: std::time::Duration,
+    /// Shell command to run on every change, the change is passed as JSON to its stdin
+    #[clap(short, long)]
+    pub exec: Option<String>,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Args)]
+pub struct Codegen {
+    #[clap(short, long, arg_enum)]
+    pub lang: Language,
+    /// File to write generated code to
+    #[clap(short, long, parse(from_os_str))]
+    pub out: std::path::PathBuf,
     #[clap(flatten)]
     pub project: Project,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, ArgEnum)]
+pub enum Language {
+    Swift,
+    Kotlin,
+    Typescript,
+    Rust,
+}
+
+#[derive(Debug, Args)]
+pub struct Defaults {
+    #[clap(short, long, arg_enum)]
+    pub format: DefaultsFormat,
+    /// Template JSON file to use instead of fetching the project template
+    #[clap(short, long, parse(from_os_str))]
+    pub template: Option<std::path::PathBuf>,
+    /// File to write defaults to, printed to stdout if omitted
+    #[clap(short, long, parse(from_os_str))]
+    pub out: Option<std::path::PathBuf>,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Args)]
+pub struct WhereUsed {
+    /// Condition to find parameters for
+    #[clap(short, long, required_unless_present = "name", conflicts_with = "name")]
+    pub condition: Option<String>,
+    /// Parameter to list conditions for in priority order
+    #[clap(short, long)]
+    pub name: Option<String>,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Args)]
+pub struct Edit {
+    /// Parameter to edit, the whole template is edited if omitted
+    #[clap(short, long)]
+    pub name: Option<String>,
+    #[clap(short, long, arg_enum, default_value = "json")]
+    pub format: EditFormat,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ArgEnum)]
+pub enum EditFormat {
+    Json,
+    Yaml,
+}
+
+#[derive(Debug, Args)]
+pub struct ImportDefaults {
+    /// iOS plist, Android defaults XML or JSON file
+    #[clap(parse(from_os_str))]
+    pub file: std::path::PathBuf,
+    /// Group to put added parameters to
+    #[clap(short, long)]
+    pub group: Option<String>,
+    /// Add all missing parameters without asking
+    #[clap(short, long)]
+    pub yes: bool,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ArgEnum)]
+pub enum DefaultsFormat {
+    Plist,
+    AndroidXml,
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct Show {
+    #[clap(flatten)]
+    pub filter: ShowFilter,
+    /// Show parameters matching --name as a single table with a row per project
+    #[clap(long, requires = "name", conflicts_with = "output")]
+    pub matrix: bool,
+    /// Output format
+    #[clap(short, long, arg_enum, default_value = "table")]
+    pub output: OutputFormat,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Args, Default)]
+pub struct ShowFilter {
+    /// Show parameters with matching name. Glob pattern or regex wrapped in slashes, e.g. /^ios_/
+    #[clap(short, long)]
+    pub name: Option<NamePattern>,
+    /// Show parameters from group
+    #[clap(short, long)]
+    pub group: Option<String>,
+    /// Show parameters with value type
+    #[clap(short = 't', long = "type", arg_enum)]
+    pub value_type: Option<ParameterValueType>,
+    /// Show parameters with a value for condition
+    #[clap(short, long)]
+    pub condition: Option<String>,
+    /// Show parameters with conditional values which differ from default value
+    #[clap(long)]
+    pub changed_from_default: bool,
+    /// Show only conditions
+    #[clap(long)]
+    pub conditions_only: bool,
+}
+
 #[derive(Debug, Args)]
 pub struct Migrate {
     /// Project to move parameters from
@@ -87,6 +478,39 @@ pub struct Migrate {
     pub source: String,
     #[clap(short, long)]
     pub projects: Option<Vec<String>>,
+    #[clap(flatten)]
+    pub options: MigrateOptions,
+}
+
+#[derive(Debug, Args, Default)]
+pub struct MigrateOptions {
+    /// Migrate conditional values and create conditions they depend on
+    #[clap(long)]
+    pub with_conditions: bool,
+    /// add: add missing parameters, sync: also update parameters which differ from source,
+    /// mirror: also delete parameters which do not exist in source
+    #[clap(long, arg_enum, default_value = "add")]
+    pub mode: MigrateMode,
+    /// Migrate only parameters with matching name. Glob pattern or regex wrapped in slashes
+    #[clap(long)]
+    pub include: Vec<NamePattern>,
+    /// Skip parameters with matching name. Glob pattern or regex wrapped in slashes
+    #[clap(long)]
+    pub exclude: Vec<NamePattern>,
+    /// Migrate only parameters from the group
+    #[clap(short, long)]
+    pub group: Vec<String>,
+    /// Select parameters to migrate from the list instead of reviewing them one by one
+    #[clap(long)]
+    pub pick: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ArgEnum, Default)]
+pub enum MigrateMode {
+    #[default]
+    Add,
+    Sync,
+    Mirror,
 }
 
 #[derive(Debug, Subcommand)]
@@ -100,7 +524,84 @@ pub enum Config {
     #[clap(name = "rm")]
     Remove { name: String },
     /// Show configuration
-    Show(Project),
+    Show(ShowConfig),
+}
+
+#[derive(Debug, Args)]
+pub struct ShowConfig {
+    #[clap(flatten)]
+    pub project: Project,
+    /// Output format
+    #[clap(short, long, arg_enum, default_value = "table")]
+    pub output: OutputFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ArgEnum)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Yaml,
+    Csv,
+    Markdown,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Group {
+    /// Show parameter groups
+    List(Project),
+    /// Rename parameter group
+    Rename(RenameGroup),
+    /// Change parameter group description
+    Describe(DescribeGroup),
+    /// Delete parameter group
+    Delete(DeleteGroup),
+    /// Move all parameters from one group to another
+    Merge(MergeGroups),
+}
+
+#[derive(Debug, Args)]
+pub struct RenameGroup {
+    /// Group to rename
+    #[clap(short, long)]
+    pub name: String,
+    /// New group name
+    #[clap(long)]
+    pub new_name: String,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Args)]
+pub struct DescribeGroup {
+    /// Group to describe
+    #[clap(short, long)]
+    pub name: String,
+    /// Group description. Empty description removes existing one
+    #[clap(short, long)]
+    pub description: Option<String>,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Args)]
+pub struct DeleteGroup {
+    /// Group to delete
+    #[clap(short, long)]
+    pub name: String,
+    #[clap(flatten)]
+    pub project: Project,
+}
+
+#[derive(Debug, Args)]
+pub struct MergeGroups {
+    /// Group to move parameters from. It will be deleted after merge
+    #[clap(long)]
+    pub from: String,
+    /// Group to move parameters to
+    #[clap(long)]
+    pub into: String,
+    #[clap(flatten)]
+    pub project: Project,
 }
 
 #[derive(Debug, Args)]
@@ -116,7 +617,7 @@ pub struct AddProject {
     pub project_number: String,
 }
 
-#[derive(Debug, Args)]
+#[derive(Debug, Args, Default)]
 pub struct Project {
     /// Specify single project for command
     #[clap(short, long)]
@@ -124,3 +625,188 @@ pub struct Project {
     #[clap(short, long)]
     pub main: Option<String>,
 }
+
+impl Schedule {
+    pub fn take_project(&mut self) -> Project {
+        std::mem::take(&mut self.project)
+    }
+}
+
+impl Rollout {
+    pub fn parameter_name(&self) -> &str {
+        match &self.subcommand {
+            Some(RolloutSubcommand::Bump(bump)) => &bump.name,
+            None => self.name.as_deref().unwrap(),
+        }
+    }
+
+    pub fn condition_name(&self) -> String {
+        let condition = match &self.subcommand {
+            Some(RolloutSubcommand::Bump(bump)) => &bump.condition,
+            None => &self.condition,
+        };
+        condition
+            .clone()
+            .unwrap_or_else(|| format!("{}_rollout", self.parameter_name()))
+    }
+
+    pub fn is_auto_confirmed(&self) -> bool {
+        match &self.subcommand {
+            Some(RolloutSubcommand::Bump(bump)) => bump.yes,
+            None => self.yes,
+        }
+    }
+
+    pub fn take_project(&mut self) -> Project {
+        match &mut self.subcommand {
+            Some(RolloutSubcommand::Bump(bump)) => std::mem::take(&mut bump.project),
+            None => std::mem::take(&mut self.project),
+        }
+    }
+}
+
+impl ParameterValues {
+    pub fn has_values(&self) -> bool {
+        self.value_type.is_some()
+            || self.default.is_some()
+            || self.use_in_app_default
+            || !self.conditional.is_empty()
+            || self.group.is_some()
+    }
+}
+
+impl FromStr for ConditionalValue {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.split_once('=') {
+            Some((condition, value)) if !condition.is_empty() => Ok(Self {
+                condition: condition.to_string(),
+                value: value.to_string(),
+            }),
+            _ => Err(Error {
+                message: format!(
+                    "Conditional value {} must have <condition>=<value> format",
+                    value
+                ),
+            }),
+        }
+    }
+}
+
+impl Cli {
+    /// Logs must not be mixed with structured output printed to stdout
+    pub fn is_structured_output(&self) -> bool {
+        let output = match &self.command {
+            Command::Show(arguments) => arguments.output,
+            Command::Config(Config::Show(arguments)) => arguments.output,
+            Command::Log(arguments) => arguments.output,
+            Command::Defaults(arguments) if arguments.out.is_none() => OutputFormat::Json,
+            _ => OutputFormat::Table,
+        };
+        output != OutputFormat::Table
+    }
+}
+
+impl Group {
+    pub fn take_project(&mut self) -> Project {
+        let project = match self {
+            Group::List(project) => project,
+            Group::Rename(arguments) => &mut arguments.project,
+            Group::Describe(arguments) => &mut arguments.project,
+            Group::Delete(arguments) => &mut arguments.project,
+            Group::Merge(arguments) => &mut arguments.project,
+        };
+        std::mem::take(project)
+    }
+}
+
+fn parse_date_time(value: &str) -> Result<DateTime<Utc>, Error> {
+    parse_date_time_with_day_time(value, NaiveTime::from_hms_opt(0, 0, 0).unwrap())
+}
+
+/// Date without time is parsed as the end of the day, so the day is included.
+fn parse_end_date_time(value: &str) -> Result<DateTime<Utc>, Error> {
+    let end_of_day = NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap();
+    parse_date_time_with_day_time(value, end_of_day)
+}
+
+fn parse_date_time_with_day_time(value: &str, day_time: NaiveTime) -> Result<DateTime<Utc>, Error> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|date_time| date_time.with_timezone(&Utc))
+        .or_else(|_| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map(|date| DateTime::from_naive_utc_and_offset(date.and_time(day_time), Utc))
+        })
+        .map_err(|_| Error {
+            message: format!(
+                "Invalid time {}, expected RFC 3339 or 2022-05-01 format",
+                value
+            ),
+        })
+}
+
+fn parse_duration(value: &str) -> Result<std::time::Duration, Error> {
+    let (number, multiplier) = match value.char_indices().last() {
+        Some((index, 's')) => (&value[..index], 1),
+        Some((index, 'm')) => (&value[..index], 60),
+        Some((index, 'h')) => (&value[..index], 60 * 60),
+        _ => (value, 1),
+    };
+    let seconds = number
+        .parse::<u64>()
+        .ok()
+        .filter(|number| *number > 0)
+        .and_then(|number| number.checked_mul(multiplier));
+    match seconds {
+        Some(seconds) => Ok(std::time::Duration::from_secs(seconds)),
+        None => Err(Error {
+            message: format!("Invalid interval {}, expected 30s, 5m or 1h format", value),
+        }),
+    }
+}
+
+fn parse_positive_count(value: &str) -> Result<usize, Error> {
+    match value.parse::<usize>() {
+        Ok(count) if count > 0 => Ok(count),
+        _ => Err(Error {
+            message: format!("Invalid count {}, expected number greater than 0", value),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overflowing_interval_is_rejected() {
+        assert_eq!(
+            parse_duration("5m").unwrap(),
+            std::time::Duration::from_secs(300)
+        );
+        assert!(parse_duration("99999999999999999h").is_err());
+        assert!(parse_duration("0s").is_err());
+    }
+
+    #[test]
+    fn zero_backups_count_is_rejected() {
+        assert!(parse_positive_count("0").is_err());
+        assert!(parse_positive_count("-1").is_err());
+        assert_eq!(parse_positive_count("3").unwrap(), 3);
+    }
+
+    #[test]
+    fn until_date_includes_whole_day() {
+        let since = parse_date_time("2026-10-18").unwrap();
+        let until = parse_end_date_time("2026-10-18").unwrap();
+        assert_eq!(since.to_rfc3339(), "2026-10-18T00:00:00+00:00");
+        assert_eq!(until.to_rfc3339(), "2026-10-18T23:59:59.999999999+00:00");
+        assert_eq!(
+            parse_end_date_time("2026-10-18T10:00:00Z")
+                .unwrap()
+                .to_rfc3339(),
+            "2026-10-18T10:00:00+00:00"
+        );
+    }
+}
@@ -2,8 +2,13 @@ use super::operator::{BinaryOperator, Operator, SetOperator};
 use crate::editor::Editor;
 use crate::error::{Error, Result};
 use crate::io::{self, InputReader};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
 use color_eyre::owo_colors::OwoColorize;
 use enum_iterator::IntoEnumIterator;
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tracing::warn;
 
 const ALL_SET_OPERATORS_EXCEPT_IN: [SetOperator; 10] = [
     SetOperator::Contains,
@@ -58,13 +63,25 @@ impl<'a, E: Editor> ExpressionBuilder<'a, E> {
             }
             ExpressionListItem::DeviceDateTime => {
                 let operator =
-                    Self::select_operator(&[BinaryOperator::LessEq, BinaryOperator::More])?;
-                let expression = Expression {
-                    name: "device.dateTime",
-                    operator,
-                    value: self.select_single_condition_value("device date time"),
+                    Self::select_operator(&[BinaryOperator::MoreEq, BinaryOperator::Less])?;
+                let date_time = self.select_single_condition_value(
+                    "device date time (2022-05-01T10:00:00 or with offset 2022-05-01T10:00:00+02:00)",
+                );
+                let time_zone = if DateTimeExpression::has_offset(&date_time) {
+                    None
+                } else {
+                    let time_zone = self.select_single_condition_value(
+                        "time zone (America/Los_Angeles) or leave empty to use device time zone",
+                    );
+                    Some(time_zone).filter(|time_zone| !time_zone.is_empty())
                 };
-                Some(expression.to_string())
+                match DateTimeExpression::new(operator, &date_time, time_zone) {
+                    Ok(expression) => Some(expression.to_string()),
+                    Err(error) => {
+                        warn!("{}", error.message.yellow());
+                        None
+                    }
+                }
             }
             ExpressionListItem::DeviceCountry => {
                 let expression = Expression {
@@ -82,6 +99,24 @@ impl<'a, E: Editor> ExpressionBuilder<'a, E> {
                 };
                 Some(expression.to_string())
             }
+            ExpressionListItem::Percent => {
+                let percent = self.select_single_condition_value("percentage of users");
+                let seed = self.select_single_condition_value("seed or leave empty");
+                let seed = Some(seed).filter(|seed| !seed.is_empty());
+                let expression = percent
+                    .parse::<f64>()
+                    .map_err(|_| Error {
+                        message: format!("{} is not a number", percent),
+                    })
+                    .and_then(|percent| PercentExpression::new(percent, seed));
+                match expression {
+                    Ok(expression) => Some(expression.to_string()),
+                    Err(error) => {
+                        warn!("{}", error.message.yellow());
+                        None
+                    }
+                }
+            }
             ExpressionListItem::AppBuild => {
                 let app_id_expr = Self::build_app_id_expr(self.app_ids)?;
                 let expression =
@@ -174,27 +209,167 @@ pub fn replace_app_id(expression: &mut String, app_ids: &[String]) -> Result<()>
         return Ok(());
     }
     let app_id_start_index = index.unwrap() + search_str.len();
-    let app_id_end_index = expression[app_id_start_index..].find('\'').map(|i| i - 1);
-    if app_id_end_index.is_none() {
+    let app_id_length = expression[app_id_start_index..].find('\'');
+    if app_id_length.is_none() {
         return Ok(());
     }
-    let app_id_end_index = app_id_end_index.unwrap() + app_id_start_index;
-    let platform = expression[app_id_start_index..app_id_end_index]
-        .split(':')
-        .nth(2)
-        .unwrap();
+    let app_id_end_index = app_id_start_index + app_id_length.unwrap();
+    let app_id = &expression[app_id_start_index..app_id_end_index];
+    let platform = app_id.split(':').nth(2).ok_or_else(|| Error {
+        message: format!("Malformed app ID {} in condition expression", app_id),
+    })?;
     let replacement = app_ids
         .iter()
-        .find(|app_id| app_id.split(':').nth(2).unwrap() == platform);
+        .find(|app_id| app_id.split(':').nth(2) == Some(platform));
     if replacement.is_none() {
         let message =
             format!("App ID for compatible {platform} platform was not found for this project");
         return Err(Error { message });
     }
-    expression.replace_range(app_id_start_index..=app_id_end_index, replacement.unwrap());
+    expression.replace_range(app_id_start_index..app_id_end_index, replacement.unwrap());
     Ok(())
 }
 
+/// Condition which is true for the percentage of users, optionally randomized with the seed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PercentExpression {
+    pub seed: Option<String>,
+    pub percent: f64,
+}
+
+impl PercentExpression {
+    pub fn new(percent: f64, seed: Option<String>) -> Result<Self> {
+        if !(0.0..=100.0).contains(&percent) {
+            return Err(Error {
+                message: format!("Percentage must be between 0 and 100, got {}", percent),
+            });
+        }
+        if seed
+            .as_ref()
+            .map(|seed| seed.contains('\''))
+            .unwrap_or(false)
+        {
+            return Err(Error::new("Seed must not contain quotes"));
+        }
+        Ok(Self { seed, percent })
+    }
+}
+
+impl Display for PercentExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.seed {
+            Some(seed) => write!(f, "percent('{}') <= {}", seed, self.percent),
+            None => write!(f, "percent <= {}", self.percent),
+        }
+    }
+}
+
+impl FromStr for PercentExpression {
+    type Err = Error;
+
+    fn from_str(expression: &str) -> Result<Self> {
+        let regex = Regex::new(r"^percent(?:\('([^']*)'\))?\s*<=\s*(\d+(?:\.\d+)?)$").unwrap();
+        let captures = regex.captures(expression.trim()).ok_or_else(|| Error {
+            message: format!("Expression {} is not a percentage rollout", expression),
+        })?;
+        let seed = captures.get(1).map(|seed| seed.as_str().to_string());
+        let percent = captures[2].parse().unwrap();
+        Self::new(percent, seed)
+    }
+}
+
+/// Condition comparing device date time with the moment.
+/// Moment without time zone is compared with device local time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateTimeExpression {
+    operator: BinaryOperator,
+    date_time: NaiveDateTime,
+    time_zone: Option<String>,
+}
+
+impl DateTimeExpression {
+    /// Parses `date_time` in RFC 3339 format, which is converted to UTC,
+    /// or without offset, in this case it is compared in `time_zone` or device time zone.
+    pub fn new(
+        operator: BinaryOperator,
+        date_time: &str,
+        time_zone: Option<String>,
+    ) -> Result<Self> {
+        let date_time = date_time.trim();
+        if let Ok(date_time) = DateTime::parse_from_rfc3339(date_time) {
+            if time_zone.is_some() {
+                return Err(Error::new(
+                    "Time zone can't be used with date time which has offset",
+                ));
+            }
+            return Ok(Self {
+                operator,
+                date_time: date_time.naive_utc(),
+                time_zone: Some("UTC".to_string()),
+            });
+        }
+        let date_time = NaiveDateTime::parse_from_str(date_time, "%Y-%m-%dT%H:%M:%S")
+            .or_else(|_| NaiveDateTime::parse_from_str(date_time, "%Y-%m-%dT%H:%M"))
+            .or_else(|_| {
+                NaiveDate::parse_from_str(date_time, "%Y-%m-%d")
+                    .map(|date| date.and_time(NaiveTime::MIN))
+            })
+            .map_err(|_| Error {
+                message: format!(
+                    "Invalid date time {}, expected 2022-05-01T10:00:00 or RFC 3339 format",
+                    date_time
+                ),
+            })?;
+        let time_zone_regex = Regex::new(r"^[A-Za-z_]+(/[A-Za-z0-9_+\-]+)*$").unwrap();
+        if let Some(time_zone) = time_zone.as_ref() {
+            if !time_zone_regex.is_match(time_zone) {
+                return Err(Error {
+                    message: format!(
+                        "Invalid time zone {}, expected America/Los_Angeles format",
+                        time_zone
+                    ),
+                });
+            }
+        }
+        Ok(Self {
+            operator,
+            date_time,
+            time_zone,
+        })
+    }
+
+    pub fn has_offset(date_time: &str) -> bool {
+        DateTime::parse_from_rfc3339(date_time.trim()).is_ok()
+    }
+
+    /// Moment is comparable with `other` only if both use the same time zone.
+    pub fn is_before(&self, other: &DateTimeExpression) -> Option<bool> {
+        if self.time_zone == other.time_zone {
+            Some(self.date_time < other.date_time)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for DateTimeExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let date_time = self.date_time.format("%Y-%m-%dT%H:%M:%S");
+        match &self.time_zone {
+            Some(time_zone) => write!(
+                f,
+                "device.dateTime {} dateTime('{}', '{}')",
+                self.operator, date_time, time_zone
+            ),
+            None => write!(
+                f,
+                "device.dateTime {} dateTime('{}')",
+                self.operator, date_time
+            ),
+        }
+    }
+}
+
 #[derive(IntoEnumIterator)]
 enum ExpressionListItem {
     AppBuild,
@@ -205,6 +380,7 @@ enum ExpressionListItem {
     DeviceDateTime,
     DeviceLanguage,
     DeviceOS,
+    Percent,
 }
 
 struct Expression<O: Operator> {
@@ -224,6 +400,7 @@ impl<'a> From<ExpressionListItem> for &'static str {
             ExpressionListItem::DeviceLanguage => "Device language",
             ExpressionListItem::DeviceOS => "Device OS",
             ExpressionListItem::DeviceDateTime => "Device date time",
+            ExpressionListItem::Percent => "User in random percentile",
         }
     }
 }
@@ -233,3 +410,67 @@ impl<O: Operator> ToString for Expression<O> {
         self.operator.to_condition(self.name, &self.value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percent_expression_round_trip() {
+        let expression = PercentExpression::new(12.5, Some("checkout".to_string())).unwrap();
+        assert_eq!(expression.to_string(), "percent('checkout') <= 12.5");
+        assert_eq!(
+            PercentExpression::from_str("percent('checkout') <= 12.5").unwrap(),
+            expression
+        );
+
+        let expression = PercentExpression::from_str("percent <= 25").unwrap();
+        assert_eq!(expression.seed, None);
+        assert_eq!(expression.to_string(), "percent <= 25");
+
+        assert!(PercentExpression::new(101.0, None).is_err());
+        assert!(PercentExpression::from_str("device.os == 'ios'").is_err());
+    }
+
+    #[test]
+    fn date_time_expression_formatting() {
+        let expression =
+            DateTimeExpression::new(BinaryOperator::MoreEq, "2022-05-01T10:00:00+02:00", None)
+                .unwrap();
+        assert_eq!(
+            expression.to_string(),
+            "device.dateTime >= dateTime('2022-05-01T08:00:00', 'UTC')"
+        );
+
+        let time_zone = Some("America/Los_Angeles".to_string());
+        let expression =
+            DateTimeExpression::new(BinaryOperator::Less, "2022-05-01T10:00", time_zone).unwrap();
+        assert_eq!(
+            expression.to_string(),
+            "device.dateTime < dateTime('2022-05-01T10:00:00', 'America/Los_Angeles')"
+        );
+
+        let expression = DateTimeExpression::new(BinaryOperator::Less, "2022-05-01", None).unwrap();
+        assert_eq!(
+            expression.to_string(),
+            "device.dateTime < dateTime('2022-05-01T00:00:00')"
+        );
+        assert!(DateTimeExpression::new(BinaryOperator::Less, "01.05.2022", None).is_err());
+    }
+
+    #[test]
+    fn replace_app_id_rejects_malformed_id() {
+        let app_ids = vec!["1:222:ios:333".to_string(), "1:222:android:444".to_string()];
+        let mut expression = "app.id == '1:111:android:555' && device.os == 'android'".to_string();
+        replace_app_id(&mut expression, &app_ids).unwrap();
+        assert_eq!(
+            expression,
+            "app.id == '1:222:android:444' && device.os == 'android'"
+        );
+
+        let mut expression = "app.id == 'malformed'".to_string();
+        assert!(replace_app_id(&mut expression, &app_ids).is_err());
+        let mut expression = "app.id == ''".to_string();
+        assert!(replace_app_id(&mut expression, &app_ids).is_err());
+    }
+}
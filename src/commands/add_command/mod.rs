@@ -1,23 +1,27 @@
+use crate::cli::ParameterValues;
 use crate::commands::command::Command;
 use crate::config::Project;
 use crate::editor::Editor;
 use crate::error::{Error, Result};
 use crate::io::{self, InputReader};
 use crate::network::{NetworkService, ResponseWithEtag};
-use crate::remote_config::{Condition, Parameter, RemoteConfig};
+use crate::remote_config::{Condition, Parameter, ParameterGroup, RemoteConfig};
+use crate::schema::Schemas;
 use async_trait::async_trait;
 use color_eyre::owo_colors::OwoColorize;
 use parameter_builder::ParameterBuilder;
 use std::collections::HashMap;
 use tracing::info;
 
-mod expression_builder;
-mod operator;
+pub mod expression_builder;
+pub mod operator;
 pub mod parameter_builder;
 
 pub struct AddCommand<NS: NetworkService, E: Editor> {
     name: Option<String>,
     description: Option<String>,
+    values: ParameterValues,
+    schemas: Schemas,
     network_service: NS,
     input_reader: InputReader<E>,
 }
@@ -32,12 +36,16 @@ impl<NS: NetworkService, E: Editor> AddCommand<NS, E> {
     pub fn new(
         name: Option<String>,
         description: Option<String>,
+        values: ParameterValues,
+        schemas: Schemas,
         network_service: NS,
         input_reader: InputReader<E>,
     ) -> Self {
         Self {
             name,
             description,
+            values,
+            schemas,
             network_service,
             input_reader,
         }
@@ -58,6 +66,7 @@ impl<NS: NetworkService, E: Editor> AddCommand<NS, E> {
         let new_parameter = NewParameter {
             name: name.clone(),
             parameter: parameter.clone(),
+            group: self.values.group.clone(),
         };
         self.add_parameter(new_parameter.clone(), response, main_project, action)
             .await?;
@@ -69,11 +78,15 @@ impl<NS: NetworkService, E: Editor> AddCommand<NS, E> {
             "Add default and conditional values",
             "Add custom values",
         ];
-        let selected_option = io::request_select_item_in_list(
-            "Select how to apply new parameter to other projects:",
-            options.iter().copied(),
-            None,
-        );
+        let selected_option = if self.values.has_values() {
+            Some(1)
+        } else {
+            io::request_select_item_in_list(
+                "Select how to apply new parameter to other projects:",
+                options.iter().copied(),
+                None,
+            )
+        };
         match selected_option {
             None => {}
             Some(0) => {
@@ -169,13 +182,45 @@ impl<NS: NetworkService, E: Editor> AddCommand<NS, E> {
             response
                 .data
                 .extend_conditions(selected_conditions, index + 1, &project.app_ids)?;
-            let new_parameter = NewParameter { name, parameter };
+            let new_parameter = NewParameter {
+                name,
+                parameter,
+                group: new_parameter.group.clone(),
+            };
             self.add_parameter(new_parameter, response, project, action)
                 .await?;
         }
         Ok(())
     }
 
+    fn build_parameter(
+        &mut self,
+        app_ids: &[String],
+        conditions: &mut Vec<Condition>,
+    ) -> Result<(String, Parameter)> {
+        if !self.values.has_values() {
+            let parameter = ParameterBuilder::start_flow(
+                self.name.take(),
+                self.description.take(),
+                &mut self.input_reader,
+                app_ids,
+                conditions,
+            );
+            return Ok(parameter);
+        }
+        let name = self
+            .name
+            .take()
+            .ok_or_else(|| Error::new("Parameter name must be passed with --name argument."))?;
+        parameter_builder::build_from_values(
+            name,
+            self.description.take(),
+            &self.values,
+            None,
+            conditions,
+        )
+    }
+
     async fn add_parameter(
         &mut self,
         new_parameter: NewParameter,
@@ -186,8 +231,8 @@ impl<NS: NetworkService, E: Editor> AddCommand<NS, E> {
         let remote_config = &mut response.data;
         let parameter_name = &new_parameter.name;
         let parameter = new_parameter.parameter;
-        let map_with_parameter = remote_config.get_map_for_existing_parameter(parameter_name);
-        match (map_with_parameter.as_ref(), action) {
+        self.schemas.check(parameter_name, &parameter)?;
+        match (remote_config.find_parameter(parameter_name), action) {
             (Some(_), Action::Add) => {
                 let message = format!(
                     "Parameter with name {} already exists! Do you want te replace it? [Y,n]",
@@ -198,9 +243,8 @@ impl<NS: NetworkService, E: Editor> AddCommand<NS, E> {
                     return Err(Error::new("Operation was canceled."));
                 }
             }
-            (Some(map), Action::Update) => {
-                let parameter = map.get(parameter_name).unwrap();
-                parameter.preview(parameter_name, "Previous parameter values", None);
+            (Some((_, existing_parameter)), Action::Update) => {
+                existing_parameter.preview(parameter_name, "Previous parameter values", None);
             }
             _ => {}
         }
@@ -209,20 +253,11 @@ impl<NS: NetworkService, E: Editor> AddCommand<NS, E> {
             Action::Add => "Parameter will be added",
             Action::Update => "Updated parameter values",
         };
-        parameter.preview(parameter_name, title, None);
+        parameter.preview(parameter_name, title, new_parameter.group.as_deref());
         if !self.input_reader.ask_confirmation("Confirm: [Y,n]") {
             return Err(Error::new("Operation was canceled."));
         }
-        match map_with_parameter {
-            Some(map) => {
-                map.insert(new_parameter.name, parameter);
-            }
-            None => {
-                remote_config
-                    .parameters
-                    .insert(new_parameter.name, parameter);
-            }
-        }
+        remote_config.put_parameter(new_parameter.name, parameter, new_parameter.group);
         self.network_service
             .update_remote_config(project, response.data, response.etag)
             .await?;
@@ -235,14 +270,13 @@ impl<NS: NetworkService + Send, E: Editor + Send> Command for AddCommand<NS, E>
     async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
         info!("Running for {} project", &project.name);
         let mut response = self.network_service.get_remote_config(project).await?;
-        let (name, parameter) = ParameterBuilder::start_flow(
-            self.name.take(),
-            self.description.take(),
-            &mut self.input_reader,
-            &project.app_ids,
-            &mut response.data.conditions,
-        );
-        let new_parameter = NewParameter { name, parameter };
+        let (name, parameter) =
+            self.build_parameter(&project.app_ids, &mut response.data.conditions)?;
+        let new_parameter = NewParameter {
+            name,
+            parameter,
+            group: self.values.group.clone(),
+        };
         self.add_parameter(new_parameter, response, project, Action::Add)
             .await
     }
@@ -254,13 +288,8 @@ impl<NS: NetworkService + Send, E: Editor + Send> Command for AddCommand<NS, E>
         info!("Running for {} project", &main_project.name);
         let mut response = self.network_service.get_remote_config(main_project).await?;
 
-        let (name, parameter) = ParameterBuilder::start_flow(
-            self.name.take(),
-            self.description.take(),
-            &mut self.input_reader,
-            &main_project.app_ids,
-            &mut response.data.conditions,
-        );
+        let (name, parameter) =
+            self.build_parameter(&main_project.app_ids, &mut response.data.conditions)?;
 
         self.apply_parameter_to_projects(name, parameter, projects, response, Action::Add)
             .await
@@ -268,6 +297,32 @@ impl<NS: NetworkService + Send, E: Editor + Send> Command for AddCommand<NS, E>
 }
 
 impl RemoteConfig {
+    /// Moves parameter to `group` if passed,
+    /// otherwise replaces it where it is or inserts it to the root.
+    pub fn put_parameter(&mut self, name: String, parameter: Parameter, group: Option<String>) {
+        match (group, self.get_map_for_existing_parameter(&name)) {
+            (Some(group_name), map) => {
+                if let Some(map) = map {
+                    map.remove(&name);
+                }
+                self.parameter_groups
+                    .entry(group_name)
+                    .or_insert_with(|| ParameterGroup {
+                        description: None,
+                        parameters: HashMap::new(),
+                    })
+                    .parameters
+                    .insert(name, parameter);
+            }
+            (None, Some(map)) => {
+                map.insert(name, parameter);
+            }
+            (None, None) => {
+                self.parameters.insert(name, parameter);
+            }
+        }
+    }
+
     fn selected_conditions_map(
         &self,
         parameter: &Parameter,
@@ -312,6 +367,7 @@ impl RemoteConfig {
 struct NewParameter {
     name: String,
     parameter: Parameter,
+    group: Option<String>,
 }
 
 struct GenerationalCondition {
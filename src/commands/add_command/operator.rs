@@ -5,7 +5,7 @@ pub trait Operator: Display {
     fn to_condition(&self, condition_name: &str, value: &Self::Item) -> String;
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum BinaryOperator {
     Less,
     LessEq,
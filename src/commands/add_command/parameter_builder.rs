@@ -1,4 +1,5 @@
 use super::expression_builder::ExpressionBuilder;
+use crate::cli::ParameterValues;
 use crate::editor::Editor;
 use crate::error::{Error, Result};
 use crate::io::{self, InputReader};
@@ -256,6 +257,155 @@ impl<'a, E: Editor> ParameterBuilder<'a, E> {
     }
 }
 
+pub fn validate_name(name: String) -> Result<String> {
+    Parts::validate_name(name).map_err(Error::new)
+}
+
+/// Validates name, value type and values of the parameter built outside of the builder.
+pub fn validate_parameter(
+    name: &str,
+    parameter: &Parameter,
+    conditions: &[Condition],
+) -> Result<()> {
+    Parts::validate_name(name.to_string()).map_err(|error| Error {
+        message: format!("Invalid name {}: {}", name, error),
+    })?;
+    if parameter.value_type == ParameterValueType::Unspecified {
+        return Err(Error {
+            message: format!("Value type of {} parameter must be specified.", name),
+        });
+    }
+    let mut values = parameter
+        .conditional_values
+        .iter()
+        .map(|(condition, value)| (Some(condition), value))
+        .collect::<Vec<_>>();
+    values.sort_by_key(|(condition, _)| *condition);
+    for (condition, value) in parameter
+        .default_value
+        .iter()
+        .map(|value| (None, value))
+        .chain(values)
+    {
+        if let Some(condition) = condition {
+            if !conditions.iter().any(|cond| &cond.name == condition) {
+                return Err(Error {
+                    message: format!(
+                        "Condition {} of {} parameter does not exist.",
+                        condition, name
+                    ),
+                });
+            }
+        }
+        let value = match value {
+            ParameterValue::Value(value) => value,
+            ParameterValue::UseInAppDefault(_) => continue,
+        };
+        Parts::validate_value(value.clone(), &parameter.value_type).map_err(|error| {
+            let value_name = condition
+                .map(|condition| format!("value for {} condition", condition))
+                .unwrap_or_else(|| "default value".to_string());
+            Error {
+                message: format!("Invalid {} of {} parameter: {}", value_name, name, error),
+            }
+        })?;
+    }
+    Ok(())
+}
+
+/// Builds parameter from command line values without prompting the user.
+/// Values which were not passed are taken from the previous parameter.
+pub fn build_from_values(
+    name: String,
+    description: Option<String>,
+    values: &ParameterValues,
+    previous: Option<&Parameter>,
+    conditions: &[Condition],
+) -> Result<(String, Parameter)> {
+    let name = validate_name(name)?;
+    let value_type = values
+        .value_type
+        .or_else(|| previous.map(|parameter| parameter.value_type))
+        .ok_or_else(|| Error::new("Value type must be passed with --type argument."))?;
+    let is_type_changed = previous
+        .map(|parameter| parameter.value_type != value_type)
+        .unwrap_or(false);
+    let default_value = match (values.use_in_app_default, values.default.as_ref()) {
+        (true, _) => Some(ParameterValue::UseInAppDefault(true)),
+        (false, Some(value)) => {
+            let value =
+                Parts::validate_value(value.clone(), &value_type).map_err(|error| Error {
+                    message: format!("Invalid default value: {}", error),
+                })?;
+            Some(ParameterValue::Value(value))
+        }
+        (false, None) => {
+            let default_value = previous.and_then(|parameter| parameter.default_value.clone());
+            if let (true, Some(ParameterValue::Value(value))) =
+                (is_type_changed, default_value.as_ref())
+            {
+                Parts::validate_value(value.clone(), &value_type).map_err(|error| Error {
+                    message: format!(
+                        "Previous default value is invalid for {:?} type, pass --default: {}",
+                        value_type, error
+                    ),
+                })?;
+            }
+            default_value
+        }
+    };
+    if default_value.is_none() {
+        return Err(Error::new(
+            "Default value must be passed with --default or --use-in-app-default argument.",
+        ));
+    }
+    let mut conditional_values = previous
+        .map(|parameter| parameter.conditional_values.clone())
+        .unwrap_or_default();
+    let mut previous_values = conditional_values
+        .iter()
+        .filter(|_| is_type_changed)
+        .filter(|(condition, _)| {
+            !values
+                .conditional
+                .iter()
+                .any(|conditional_value| &&conditional_value.condition == condition)
+        })
+        .collect::<Vec<_>>();
+    previous_values.sort_by_key(|(condition, _)| *condition);
+    for (condition_name, value) in previous_values {
+        if let ParameterValue::Value(value) = value {
+            Parts::validate_value(value.clone(), &value_type).map_err(|error| Error {
+                message: format!(
+                    "Previous value for {} condition is invalid for {:?} type, pass --conditional: {}",
+                    condition_name, value_type, error
+                ),
+            })?;
+        }
+    }
+    for conditional_value in values.conditional.iter() {
+        let condition_name = &conditional_value.condition;
+        if !conditions.iter().any(|cond| &cond.name == condition_name) {
+            return Err(Error {
+                message: format!("Condition {} does not exist.", condition_name),
+            });
+        }
+        let value = Parts::validate_value(conditional_value.value.clone(), &value_type).map_err(
+            |error| Error {
+                message: format!("Invalid value for {} condition: {}", condition_name, error),
+            },
+        )?;
+        conditional_values.insert(condition_name.clone(), ParameterValue::Value(value));
+    }
+    let parameter = Parameter {
+        default_value,
+        conditional_values,
+        description: description.or_else(|| previous.and_then(|p| p.description.clone())),
+        value_type,
+    };
+    Ok((name, parameter))
+}
+
 impl Parts {
     fn new_from_parameter(name: String, parameter: &Parameter) -> Self {
         Self {
@@ -358,3 +508,94 @@ impl Default for ParameterValue {
         Self::Value(String::new())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::ConditionalValue;
+
+    #[test]
+    fn build_from_values_validates_values() {
+        let conditions = vec![Condition {
+            name: "iOS".to_string(),
+            expression: "device.os == 'ios'".to_string(),
+            tag_color: TagColor::Blue,
+        }];
+        let mut values = ParameterValues {
+            value_type: Some(ParameterValueType::Boolean),
+            default: Some("false".to_string()),
+            conditional: vec![ConditionalValue {
+                condition: "iOS".to_string(),
+                value: "true".to_string(),
+            }],
+            ..Default::default()
+        };
+        let (_, parameter) =
+            build_from_values("flag".to_string(), None, &values, None, &conditions).unwrap();
+        assert_eq!(
+            parameter.conditional_values.get("iOS"),
+            Some(&ParameterValue::Value("true".to_string()))
+        );
+
+        values.default = Some("yes".to_string());
+        assert!(build_from_values("flag".to_string(), None, &values, None, &conditions).is_err());
+
+        values.default = Some("true".to_string());
+        values.conditional[0].condition = "Android".to_string();
+        assert!(build_from_values("flag".to_string(), None, &values, None, &conditions).is_err());
+    }
+
+    #[test]
+    fn build_from_values_validates_previous_values_for_new_type() {
+        let conditions = vec![Condition {
+            name: "iOS".to_string(),
+            expression: "device.os == 'ios'".to_string(),
+            tag_color: TagColor::Blue,
+        }];
+        let previous = Parameter {
+            default_value: Some(ParameterValue::Value("10".to_string())),
+            conditional_values: HashMap::from([(
+                "iOS".to_string(),
+                ParameterValue::Value("many".to_string()),
+            )]),
+            description: None,
+            value_type: ParameterValueType::String,
+        };
+        let mut values = ParameterValues {
+            value_type: Some(ParameterValueType::Number),
+            ..Default::default()
+        };
+        let result = build_from_values(
+            "limit".to_string(),
+            None,
+            &values,
+            Some(&previous),
+            &conditions,
+        );
+        assert!(result.unwrap_err().message.contains("iOS condition"));
+
+        values.conditional = vec![ConditionalValue {
+            condition: "iOS".to_string(),
+            value: "20".to_string(),
+        }];
+        let (_, parameter) = build_from_values(
+            "limit".to_string(),
+            None,
+            &values,
+            Some(&previous),
+            &conditions,
+        )
+        .unwrap();
+        assert_eq!(parameter.value_type, ParameterValueType::Number);
+
+        values.default = Some("ten".to_string());
+        assert!(build_from_values(
+            "limit".to_string(),
+            None,
+            &values,
+            Some(&previous),
+            &conditions
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,243 @@
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::error::{Error, Result};
+use crate::network::NetworkService;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info};
+
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+pub struct BackupCommand<NS: NetworkService> {
+    out: PathBuf,
+    keep: Option<usize>,
+    keep_days: Option<u32>,
+    network_service: NS,
+}
+
+/// Description of the backup stored next to project configs.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Manifest {
+    pub created_at: DateTime<Utc>,
+    pub projects: Vec<ManifestProject>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ManifestProject {
+    pub name: String,
+    pub project_number: String,
+    pub etag: String,
+    /// Config file name relative to the backup directory
+    pub file: String,
+}
+
+impl<NS: NetworkService> BackupCommand<NS> {
+    pub fn new(
+        out: PathBuf,
+        keep: Option<usize>,
+        keep_days: Option<u32>,
+        network_service: NS,
+    ) -> Self {
+        Self {
+            out,
+            keep,
+            keep_days,
+            network_service,
+        }
+    }
+
+    async fn run(&mut self, projects: &[Project]) -> Result<()> {
+        let created_at = Utc::now();
+        let mut configs = Vec::with_capacity(projects.len());
+        for project in projects {
+            info!("Downloading {} project", &project.name);
+            let response = self.network_service.get_remote_config(project).await?;
+            configs.push((project, response));
+        }
+
+        let directory = self
+            .out
+            .join(created_at.format("%Y%m%dT%H%M%S%.6fZ").to_string());
+        fs::create_dir_all(&self.out)
+            .and_then(|_| fs::create_dir(&directory))
+            .map_err(|error| {
+                debug!("Error: {:?}", error);
+                Error {
+                    message: format!("Failed to create backup directory {:?}.", directory),
+                }
+            })?;
+        let mut manifest = Manifest {
+            created_at,
+            projects: Vec::with_capacity(configs.len()),
+        };
+        for (project, response) in configs {
+            let file = format!("{}.json", project.project_number());
+            write_json(&directory.join(&file), &response.data)?;
+            manifest.projects.push(ManifestProject {
+                name: project.name.clone(),
+                project_number: project.project_number().to_string(),
+                etag: response.etag,
+                file,
+            });
+        }
+        write_json(&directory.join(MANIFEST_FILE_NAME), &manifest)?;
+        info!("Backup was saved to {:?}", directory);
+        self.prune()
+    }
+
+    /// Removes backups exceeding `keep` count or older than `keep_days`.
+    fn prune(&self) -> Result<()> {
+        if self.keep.is_none() && self.keep_days.is_none() {
+            return Ok(());
+        }
+        let mut backups = fs::read_dir(&self.out)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter_map(|path| {
+                let content = fs::read_to_string(path.join(MANIFEST_FILE_NAME)).ok()?;
+                let manifest = serde_json::from_str::<Manifest>(&content).ok()?;
+                Some((path, manifest.created_at))
+            })
+            .collect::<Vec<_>>();
+        backups.sort_by_key(|(_, created_at)| std::cmp::Reverse(*created_at));
+        let oldest_date = self
+            .keep_days
+            .map(|days| Utc::now() - Duration::days(days.into()));
+        for (index, (path, created_at)) in backups.iter().enumerate() {
+            let is_exceeding_count = self.keep.map(|keep| index >= keep).unwrap_or(false);
+            let is_outdated = oldest_date.map(|date| *created_at < date).unwrap_or(false);
+            if is_exceeding_count || is_outdated {
+                info!("Removing old backup {:?}", path);
+                fs::remove_dir_all(path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send> Command for BackupCommand<NS> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        self.run(std::slice::from_ref(project)).await
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        self.run(projects).await
+    }
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    let content = serde_json::to_string_pretty(value).map_err(|error| {
+        debug!("Error: {:?}", error);
+        Error::new("Failed to serialize backup.")
+    })?;
+    fs::write(path, content).map_err(|error| {
+        debug!("Error: {:?}", error);
+        Error {
+            message: format!("Failed to write backup file {:?}.", path),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::{MockNetworkService, ResponseWithEtag};
+    use crate::remote_config::{Parameter, RemoteConfig};
+
+    fn network_mock(times: usize) -> MockNetworkService {
+        let mut network_mock = MockNetworkService::new();
+        network_mock
+            .expect_get_remote_config()
+            .times(times)
+            .returning(|project| {
+                let mut config = RemoteConfig::default();
+                config
+                    .parameters
+                    .insert(project.name.to_lowercase(), Parameter::stub());
+                std::result::Result::Ok(ResponseWithEtag {
+                    etag: format!("e_tag_{}", project.project_number()),
+                    data: config,
+                })
+            });
+        network_mock
+    }
+
+    fn temp_directory(test_name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "rcm-backup-test-{}-{}",
+            std::process::id(),
+            test_name
+        ))
+    }
+
+    fn backup_directories(out: &Path) -> Vec<PathBuf> {
+        let mut directories = fs::read_dir(out)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        directories.sort();
+        directories
+    }
+
+    #[tokio::test]
+    async fn manifest_describes_backed_up_configs() {
+        let out = temp_directory("manifest");
+        let projects = [
+            Project::new("First".to_string(), "1".to_string(), vec![]),
+            Project::new("Second".to_string(), "2".to_string(), vec![]),
+        ];
+        let mut command = BackupCommand::new(out.clone(), None, None, network_mock(2));
+
+        let result = command.run(&projects).await;
+        let directories = backup_directories(&out);
+        let content = fs::read_to_string(directories[0].join(MANIFEST_FILE_NAME));
+        let config = fs::read_to_string(directories[0].join("2.json"));
+        let _ = fs::remove_dir_all(&out);
+        result.unwrap();
+
+        assert_eq!(directories.len(), 1);
+        let manifest = serde_json::from_str::<Manifest>(&content.unwrap()).unwrap();
+        let projects = manifest
+            .projects
+            .iter()
+            .map(|project| {
+                (
+                    project.name.as_str(),
+                    project.etag.as_str(),
+                    project.file.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            projects,
+            vec![
+                ("First", "e_tag_1", "1.json"),
+                ("Second", "e_tag_2", "2.json")
+            ]
+        );
+        let config = serde_json::from_str::<RemoteConfig>(&config.unwrap()).unwrap();
+        assert!(config.parameters.contains_key("second"));
+    }
+
+    #[tokio::test]
+    async fn only_latest_backups_are_kept() {
+        let out = temp_directory("keep");
+        let projects = [Project::new("First".to_string(), "1".to_string(), vec![])];
+        let mut results = Vec::new();
+        let mut first_directory = None;
+        for _ in 0..3 {
+            let mut command = BackupCommand::new(out.clone(), Some(2), None, network_mock(1));
+            results.push(command.run(&projects).await);
+            first_directory = first_directory.or_else(|| backup_directories(&out).pop());
+        }
+        let directories = backup_directories(&out);
+        let _ = fs::remove_dir_all(&out);
+
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(directories.len(), 2);
+        assert!(!directories.contains(&first_directory.unwrap()));
+    }
+}
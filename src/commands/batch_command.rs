@@ -0,0 +1,450 @@
+use super::add_command::expression_builder::replace_app_id;
+use super::add_command::parameter_builder;
+use crate::cli::{ConditionalValue, ParameterValues};
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::editor::Editor;
+use crate::error::{Error, Result};
+use crate::io::InputReader;
+use crate::network::{NetworkService, ResponseWithEtag};
+use crate::remote_config::{
+    Condition, Parameter, ParameterGroup, ParameterValueType, RemoteConfig, TagColor,
+};
+use crate::schema::Schemas;
+use async_trait::async_trait;
+use clap::ArgEnum;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+use tracing::{debug, info, warn};
+
+pub struct BatchCommand<NS: NetworkService, E: Editor> {
+    path: PathBuf,
+    schemas: Schemas,
+    network_service: NS,
+    input_reader: InputReader<E>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(tag = "op", rename_all = "kebab-case")]
+enum Operation {
+    Add(ParameterOperation),
+    Update(ParameterOperation),
+    Delete {
+        name: String,
+    },
+    MoveTo {
+        name: String,
+        group: String,
+    },
+    MoveOut {
+        name: String,
+    },
+    Rename {
+        name: String,
+        new_name: String,
+    },
+    AddCondition {
+        name: String,
+        expression: String,
+        #[serde(default, deserialize_with = "deserialize_tag_color")]
+        tag_color: Option<TagColor>,
+    },
+}
+
+#[derive(Deserialize, Debug)]
+struct ParameterOperation {
+    name: String,
+    description: Option<String>,
+    #[serde(rename = "type")]
+    value_type: Option<String>,
+    default: Option<Value>,
+    #[serde(default)]
+    use_in_app_default: bool,
+    #[serde(default)]
+    conditional: HashMap<String, Value>,
+    group: Option<String>,
+}
+
+/// Accepts colors in any case and with dashes, e.g. `blue` or `deep-orange`.
+fn deserialize_tag_color<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<TagColor>, D::Error> {
+    let color = match Option::<String>::deserialize(deserializer)? {
+        None => return Ok(None),
+        Some(color) => color,
+    };
+    let normalized = color.to_uppercase().replace('-', "_");
+    serde_json::from_value(Value::String(normalized))
+        .map(Some)
+        .map_err(|_| D::Error::custom(format!("unknown tag color {}", color)))
+}
+
+impl<NS: NetworkService, E: Editor> BatchCommand<NS, E> {
+    pub fn new(
+        path: PathBuf,
+        schemas: Schemas,
+        network_service: NS,
+        input_reader: InputReader<E>,
+    ) -> Self {
+        Self {
+            path,
+            schemas,
+            network_service,
+            input_reader,
+        }
+    }
+
+    async fn run(&mut self, projects: &[Project]) -> Result<()> {
+        let operations = self.load_operations()?;
+        if operations.is_empty() {
+            warn!("Batch file does not contain operations.");
+            return Ok(());
+        }
+        println!("Operations:");
+        for (index, operation) in operations.iter().enumerate() {
+            println!("  {}. {}", index + 1, operation);
+        }
+
+        let mut responses = Vec::with_capacity(projects.len());
+        for project in projects {
+            info!("Running for {} project", &project.name);
+            let mut response = self.network_service.get_remote_config(project).await?;
+            Self::apply_operations(&mut response, &operations, &self.schemas, project)?;
+            info!(
+                "All operations were applied to {} project in memory",
+                &project.name
+            );
+            responses.push((project, response));
+        }
+
+        let message = format!("Publish changes to {} project(s)? [Y,n]", responses.len());
+        if !self.input_reader.ask_confirmation(&message) {
+            warn!("Operation was canceled.");
+            return Ok(());
+        }
+        for (project, response) in responses {
+            info!("Publishing {} project", &project.name);
+            self.network_service
+                .update_remote_config(project, response.data, response.etag)
+                .await?;
+        }
+        Ok(())
+    }
+
+    fn load_operations(&self) -> Result<Vec<Operation>> {
+        let content = fs::read_to_string(&self.path)?;
+        let is_json = self
+            .path
+            .extension()
+            .map(|extension| extension == "json")
+            .unwrap_or(false);
+        let operations = if is_json {
+            serde_json::from_str::<Vec<Operation>>(&content).map_err(|error| error.to_string())
+        } else {
+            serde_yaml::from_str::<Vec<Operation>>(&content).map_err(|error| error.to_string())
+        };
+        operations.map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error {
+                message: format!("Failed to parse batch file: {}", error),
+            }
+        })
+    }
+
+    fn apply_operations(
+        response: &mut ResponseWithEtag<RemoteConfig>,
+        operations: &[Operation],
+        schemas: &Schemas,
+        project: &Project,
+    ) -> Result<()> {
+        for (index, operation) in operations.iter().enumerate() {
+            response
+                .data
+                .apply_operation(operation, &project.app_ids)
+                .and_then(|_| match operation {
+                    Operation::Add(operation) | Operation::Update(operation) => {
+                        match response.data.find_parameter(&operation.name) {
+                            Some((_, parameter)) => schemas.check(&operation.name, parameter),
+                            None => Ok(()),
+                        }
+                    }
+                    _ => Ok(()),
+                })
+                .map_err(|error| Error {
+                    message: format!(
+                        "Batch was aborted. Operation {} ({}) failed for {} project: {}",
+                        index + 1,
+                        operation,
+                        &project.name,
+                        error.message
+                    ),
+                })?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send, E: Editor + Send> Command for BatchCommand<NS, E> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        self.run(std::slice::from_ref(project)).await
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        self.run(projects).await
+    }
+}
+
+impl RemoteConfig {
+    fn apply_operation(&mut self, operation: &Operation, app_ids: &[String]) -> Result<()> {
+        match operation {
+            Operation::Add(operation) => {
+                if self.find_parameter(&operation.name).is_some() {
+                    return Err(Error {
+                        message: format!("Parameter {} already exists", operation.name),
+                    });
+                }
+                let values = operation.parameter_values()?;
+                let (name, parameter) = parameter_builder::build_from_values(
+                    operation.name.clone(),
+                    operation.description.clone(),
+                    &values,
+                    None,
+                    &self.conditions,
+                )?;
+                self.insert_parameter(name, parameter, operation.group.clone());
+            }
+            Operation::Update(operation) => {
+                let (group, previous) = self.remove_existing_parameter(&operation.name)?;
+                let values = operation.parameter_values()?;
+                let (name, parameter) = parameter_builder::build_from_values(
+                    operation.name.clone(),
+                    operation.description.clone(),
+                    &values,
+                    Some(&previous),
+                    &self.conditions,
+                )?;
+                self.insert_parameter(name, parameter, operation.group.clone().or(group));
+            }
+            Operation::Delete { name } => {
+                self.remove_existing_parameter(name)?;
+            }
+            Operation::MoveTo { name, group } => {
+                let (_, parameter) = self.remove_existing_parameter(name)?;
+                self.insert_parameter(name.clone(), parameter, Some(group.clone()));
+            }
+            Operation::MoveOut { name } => {
+                let (group, parameter) = self.remove_existing_parameter(name)?;
+                if group.is_none() {
+                    return Err(Error {
+                        message: format!("Parameter {} is not in a group", name),
+                    });
+                }
+                self.insert_parameter(name.clone(), parameter, None);
+            }
+            Operation::Rename { name, new_name } => {
+                let new_name = parameter_builder::validate_name(new_name.clone())?;
+                if self.find_parameter(&new_name).is_some() {
+                    return Err(Error {
+                        message: format!("Parameter {} already exists", new_name),
+                    });
+                }
+                let (group, parameter) = self.remove_existing_parameter(name)?;
+                self.insert_parameter(new_name, parameter, group);
+            }
+            Operation::AddCondition {
+                name,
+                expression,
+                tag_color,
+            } => {
+                if self
+                    .conditions
+                    .iter()
+                    .any(|condition| &condition.name == name)
+                {
+                    return Err(Error {
+                        message: format!("Condition {} already exists", name),
+                    });
+                }
+                let mut expression = expression.clone();
+                replace_app_id(&mut expression, app_ids)?;
+                self.conditions.push(Condition {
+                    name: name.clone(),
+                    expression,
+                    tag_color: tag_color.clone().unwrap_or(TagColor::Green),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn remove_existing_parameter(&mut self, name: &str) -> Result<(Option<String>, Parameter)> {
+        if let Some(parameter) = self.parameters.remove(name) {
+            return Ok((None, parameter));
+        }
+        self.parameter_groups
+            .iter_mut()
+            .find_map(|(group_name, group)| {
+                group
+                    .parameters
+                    .remove(name)
+                    .map(|parameter| (Some(group_name.clone()), parameter))
+            })
+            .ok_or_else(|| Error {
+                message: format!("Parameter {} does not exist", name),
+            })
+    }
+
+    fn insert_parameter(&mut self, name: String, parameter: Parameter, group: Option<String>) {
+        match group {
+            None => {
+                self.parameters.insert(name, parameter);
+            }
+            Some(group) => {
+                self.parameter_groups
+                    .entry(group)
+                    .or_insert_with(|| ParameterGroup {
+                        description: None,
+                        parameters: HashMap::new(),
+                    })
+                    .parameters
+                    .insert(name, parameter);
+            }
+        }
+    }
+}
+
+impl ParameterOperation {
+    fn parameter_values(&self) -> Result<ParameterValues> {
+        let value_type = self
+            .value_type
+            .as_deref()
+            .map(|value_type| {
+                ParameterValueType::from_str(value_type, true).map_err(|_| Error {
+                    message: format!("Unknown parameter type {}", value_type),
+                })
+            })
+            .transpose()?;
+        let conditional = self
+            .conditional
+            .iter()
+            .map(|(condition, value)| ConditionalValue {
+                condition: condition.clone(),
+                value: value_to_string(value),
+            })
+            .collect();
+        Ok(ParameterValues {
+            value_type,
+            default: self.default.as_ref().map(value_to_string),
+            use_in_app_default: self.use_in_app_default,
+            conditional,
+            group: self.group.clone(),
+            yes: false,
+        })
+    }
+}
+
+/// Values may be written as YAML or JSON scalars, objects are stored as JSON strings.
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(string) => string.clone(),
+        _ => value.to_string(),
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operation::Add(operation) => write!(f, "add {}", operation.name),
+            Operation::Update(operation) => write!(f, "update {}", operation.name),
+            Operation::Delete { name } => write!(f, "delete {}", name),
+            Operation::MoveTo { name, group } => write!(f, "move {} to {} group", name, group),
+            Operation::MoveOut { name } => write!(f, "move {} out of group", name),
+            Operation::Rename { name, new_name } => write!(f, "rename {} to {}", name, new_name),
+            Operation::AddCondition { name, .. } => write!(f, "add condition {}", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations_applied_in_order() {
+        let yaml = r#"
+- op: add-condition
+  name: iOS
+  expression: device.os == 'ios'
+- op: add
+  name: upload_logs
+  type: boolean
+  default: false
+  conditional:
+    iOS: true
+- op: move-to
+  name: upload_logs
+  group: Logs
+- op: rename
+  name: upload_logs
+  new_name: send_logs
+"#;
+        let operations = serde_yaml::from_str::<Vec<Operation>>(yaml).unwrap();
+        let mut config = RemoteConfig {
+            conditions: Vec::new(),
+            parameters: HashMap::new(),
+            parameter_groups: HashMap::new(),
+        };
+        for operation in operations.iter() {
+            config.apply_operation(operation, &[]).unwrap();
+        }
+
+        let (group, parameter) = config.find_parameter("send_logs").unwrap();
+        assert_eq!(group, Some("Logs"));
+        assert_eq!(parameter.value_type, ParameterValueType::Boolean);
+        assert_eq!(parameter.conditional_values.len(), 1);
+        assert!(config.find_parameter("upload_logs").is_none());
+
+        let delete_missing = Operation::Delete {
+            name: "upload_logs".to_string(),
+        };
+        assert!(config.apply_operation(&delete_missing, &[]).is_err());
+    }
+
+    #[test]
+    fn tag_colors_are_case_insensitive() {
+        let yaml = r#"
+- op: add-condition
+  name: iOS
+  expression: device.os == 'ios'
+  tag_color: deep-orange
+- op: add-condition
+  name: Android
+  expression: device.os == 'android'
+  tag_color: Blue
+- op: add-condition
+  name: Web
+  expression: device.os == 'web'
+"#;
+        let colors = serde_yaml::from_str::<Vec<Operation>>(yaml)
+            .unwrap()
+            .into_iter()
+            .map(|operation| match operation {
+                Operation::AddCondition { tag_color, .. } => tag_color,
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            colors,
+            vec![Some(TagColor::DeepOrange), Some(TagColor::Blue), None]
+        );
+        let unknown = "- op: add-condition\n  name: iOS\n  expression: 'true'\n  tag_color: gold\n";
+        assert!(serde_yaml::from_str::<Vec<Operation>>(unknown).is_err());
+    }
+}
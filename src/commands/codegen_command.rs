@@ -0,0 +1,443 @@
+use crate::cli::Language;
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::error::{Error, Result};
+use crate::network::NetworkService;
+use crate::remote_config::{Parameter, ParameterValueType, RemoteConfig};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use tracing::{debug, info, warn};
+
+const INDENT: &str = "    ";
+const SWIFT_KEYWORDS: &str =
+    "Any Self as associatedtype break case catch class continue default defer deinit do \
+     else enum extension fallthrough false fileprivate for func guard if import in init \
+     inout internal is let nil open operator private protocol public repeat rethrows \
+     return self static struct subscript super switch throw throws true try typealias var \
+     where while";
+const KOTLIN_KEYWORDS: &str =
+    "as break class continue do else false for fun if in interface is null object package \
+     return super this throw true try typealias typeof val var when while";
+const TYPESCRIPT_KEYWORDS: &str =
+    "await break case catch class const continue debugger default delete do else enum \
+     export extends false finally for function if implements import in instanceof \
+     interface let new null package private protected public return static super switch \
+     this throw true try typeof var void while with yield";
+const RUST_KEYWORDS: &str =
+    "abstract as async await become box break const continue crate do dyn else enum \
+     extern false final fn for if impl in let loop macro match mod move mut override priv \
+     pub ref return self Self static struct super trait true try type typeof unsafe \
+     unsized use virtual where while yield";
+/// Rust keywords which can't be used as raw identifiers
+const RUST_PATH_KEYWORDS: &str = "crate self Self super";
+
+pub struct CodegenCommand<NS: NetworkService> {
+    language: Language,
+    out: PathBuf,
+    network_service: NS,
+}
+
+impl<NS: NetworkService> CodegenCommand<NS> {
+    pub fn new(language: Language, out: PathBuf, network_service: NS) -> Self {
+        Self {
+            language,
+            out,
+            network_service,
+        }
+    }
+
+    async fn run(&mut self, project: &Project) -> Result<()> {
+        info!("Running for {} project", &project.name);
+        let response = self.network_service.get_remote_config(project).await?;
+        let code = generate(self.language, &project.name, &response.data)?;
+        fs::write(&self.out, code).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error {
+                message: format!("Failed to write {:?}.", self.out),
+            }
+        })?;
+        info!("Code was written to {:?}", self.out);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send> Command for CodegenCommand<NS> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        self.run(project).await
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        let project = &projects[0];
+        if projects.len() > 1 {
+            let message = format!(
+                "Code is generated from {} project, pass --project to choose another one",
+                &project.name
+            );
+            warn!("{}", message);
+        }
+        self.run(project).await
+    }
+}
+
+/// Generates key constants for all parameters, groups become nested namespaces.
+fn generate(
+    language: Language,
+    project_name: &str,
+    remote_config: &RemoteConfig,
+) -> Result<String> {
+    let mut writer = CodeWriter {
+        language,
+        code: String::new(),
+        depth: 0,
+        identifiers: vec![HashMap::new()],
+    };
+    writer.header(project_name);
+    writer.open_namespace("RemoteConfigKeys", true)?;
+    writer.constants(remote_config.parameters.iter())?;
+
+    let mut groups = remote_config.parameter_groups.iter().collect::<Vec<_>>();
+    groups.sort_by_key(|(name, _)| *name);
+    for (group_name, group) in groups {
+        writer.code.push('\n');
+        if let Some(description) = group.description.as_ref() {
+            writer.doc_comment(description);
+        }
+        writer.open_namespace(group_name, false)?;
+        writer.constants(group.parameters.iter())?;
+        writer.close_namespace();
+    }
+    writer.close_namespace();
+    Ok(writer.code)
+}
+
+struct CodeWriter {
+    language: Language,
+    code: String,
+    depth: usize,
+    /// Declared identifiers and their original names by namespace depth
+    identifiers: Vec<HashMap<String, String>>,
+}
+
+impl CodeWriter {
+    fn line(&mut self, line: &str) {
+        if !line.is_empty() {
+            self.code.push_str(&INDENT.repeat(self.depth));
+        }
+        self.code.push_str(line);
+        self.code.push('\n');
+    }
+
+    fn header(&mut self, project_name: &str) {
+        let comment = format!(
+            "Generated by rcm from {} project. Do not edit manually.",
+            project_name
+        );
+        match self.language {
+            Language::Swift => {
+                self.line(&format!("// {}", comment));
+                self.line("");
+                self.line("struct RemoteConfigKey<Value> {");
+                self.line("    let name: String");
+                self.line("}");
+            }
+            Language::Kotlin => {
+                self.line(&format!("// {}", comment));
+                self.line("");
+                self.line("@Suppress(\"unused\")");
+                self.line("class RemoteConfigKey<T>(val name: String)");
+            }
+            Language::Typescript => {
+                self.line(&format!("// {}", comment));
+                self.line("");
+                self.line("export class RemoteConfigKey<T> {");
+                self.line("  private readonly valueType?: T;");
+                self.line("");
+                self.line("  constructor(readonly name: string) {}");
+                self.line("}");
+            }
+            Language::Rust => {
+                self.line(&format!("//! {}", comment));
+                self.line("");
+                self.line("use std::marker::PhantomData;");
+                self.line("");
+                self.line("pub struct RemoteConfigKey<T> {");
+                self.line("    pub name: &'static str,");
+                self.line("    value_type: PhantomData<T>,");
+                self.line("}");
+                self.line("");
+                self.line("impl<T> RemoteConfigKey<T> {");
+                self.line("    const fn new(name: &'static str) -> Self {");
+                self.line("        Self {");
+                self.line("            name,");
+                self.line("            value_type: PhantomData,");
+                self.line("        }");
+                self.line("    }");
+                self.line("}");
+            }
+        }
+        self.line("");
+    }
+
+    fn open_namespace(&mut self, name: &str, is_root: bool) -> Result<()> {
+        let identifier = match self.language {
+            Language::Rust => self.declare(snake_case(name), name)?,
+            _ => self.declare(pascal_case(name), name)?,
+        };
+        let line = match (self.language, is_root) {
+            (Language::Swift, _) => format!("enum {} {{", identifier),
+            (Language::Kotlin, _) => format!("object {} {{", identifier),
+            (Language::Typescript, true) => format!("export const {} = {{", identifier),
+            (Language::Typescript, false) => format!("{}: {{", identifier),
+            (Language::Rust, _) => format!("pub mod {} {{", identifier),
+        };
+        self.line(&line);
+        self.depth += 1;
+        self.identifiers.push(HashMap::new());
+        if self.language == Language::Rust {
+            let path = "super::".repeat(self.depth);
+            self.line(&format!("use {}RemoteConfigKey;", path));
+            self.line("");
+        }
+        Ok(())
+    }
+
+    fn close_namespace(&mut self) {
+        self.depth -= 1;
+        self.identifiers.pop();
+        let line = match (self.language, self.depth) {
+            (Language::Typescript, 0) => "} as const;",
+            (Language::Typescript, _) => "},",
+            _ => "}",
+        };
+        self.line(line);
+    }
+
+    fn constants<'a>(
+        &mut self,
+        parameters: impl Iterator<Item = (&'a String, &'a Parameter)>,
+    ) -> Result<()> {
+        let mut parameters = parameters.collect::<Vec<_>>();
+        parameters.sort_by_key(|(name, _)| *name);
+        for (name, parameter) in parameters {
+            let identifier = match self.language {
+                Language::Rust => self.declare(snake_case(name).to_uppercase(), name)?,
+                _ => self.declare(camel_case(name), name)?,
+            };
+            if let Some(description) = parameter.description.as_ref() {
+                self.doc_comment(description);
+            }
+            let value_type = self.type_name(&parameter.value_type);
+            let line = match self.language {
+                Language::Swift => format!(
+                    "static let {} = RemoteConfigKey<{}>(name: \"{}\")",
+                    identifier, value_type, name
+                ),
+                Language::Kotlin => format!(
+                    "val {} = RemoteConfigKey<{}>(\"{}\")",
+                    identifier, value_type, name
+                ),
+                Language::Typescript => format!(
+                    "{}: new RemoteConfigKey<{}>(\"{}\"),",
+                    identifier, value_type, name
+                ),
+                Language::Rust => format!(
+                    "pub const {}: RemoteConfigKey<{}> = RemoteConfigKey::new(\"{}\");",
+                    identifier, value_type, name
+                ),
+            };
+            self.line(&line);
+        }
+        Ok(())
+    }
+
+    /// Escapes the identifier and checks that it's unique in the current namespace.
+    fn declare(&mut self, identifier: String, name: &str) -> Result<String> {
+        let identifier = self.escaped(identifier);
+        let identifiers = self.identifiers.last_mut().unwrap();
+        if let Some(existing_name) = identifiers.get(&identifier) {
+            let message = format!(
+                "{} and {} have the same identifier {} in generated code, rename one of them",
+                existing_name, name, identifier
+            );
+            return Err(Error { message });
+        }
+        identifiers.insert(identifier.clone(), name.to_string());
+        Ok(identifier)
+    }
+
+    fn escaped(&self, identifier: String) -> String {
+        let keywords = match self.language {
+            Language::Swift => SWIFT_KEYWORDS,
+            Language::Kotlin => KOTLIN_KEYWORDS,
+            Language::Typescript => TYPESCRIPT_KEYWORDS,
+            Language::Rust => RUST_KEYWORDS,
+        };
+        let is_keyword = |keywords: &str| {
+            keywords
+                .split_whitespace()
+                .any(|keyword| keyword == identifier)
+        };
+        if !is_keyword(keywords) {
+            return identifier;
+        }
+        match self.language {
+            Language::Swift | Language::Kotlin => format!("`{}`", identifier),
+            Language::Rust if !is_keyword(RUST_PATH_KEYWORDS) => {
+                format!("r#{}", identifier)
+            }
+            Language::Typescript | Language::Rust => format!("{}_", identifier),
+        }
+    }
+
+    fn doc_comment(&mut self, description: &str) {
+        let lines = description.lines().map(str::trim_end).collect::<Vec<_>>();
+        match self.language {
+            Language::Swift | Language::Rust => {
+                for line in lines {
+                    self.line(format!("/// {}", line).trim_end());
+                }
+            }
+            Language::Kotlin | Language::Typescript => {
+                self.line("/**");
+                for line in lines {
+                    self.line(format!(" * {}", line.replace("*/", "* /")).trim_end());
+                }
+                self.line(" */");
+            }
+        }
+    }
+
+    fn type_name(&self, value_type: &ParameterValueType) -> &'static str {
+        match (self.language, value_type) {
+            (Language::Swift, ParameterValueType::Boolean) => "Bool",
+            (Language::Swift, ParameterValueType::Number) => "Double",
+            (Language::Swift, _) => "String",
+            (Language::Kotlin, ParameterValueType::Boolean) => "Boolean",
+            (Language::Kotlin, ParameterValueType::Number) => "Double",
+            (Language::Kotlin, _) => "String",
+            (Language::Typescript, ParameterValueType::Boolean) => "boolean",
+            (Language::Typescript, ParameterValueType::Number) => "number",
+            (Language::Typescript, _) => "string",
+            (Language::Rust, ParameterValueType::Boolean) => "bool",
+            (Language::Rust, ParameterValueType::Number) => "f64",
+            (Language::Rust, _) => "String",
+        }
+    }
+}
+
+fn words(name: &str) -> impl Iterator<Item = &str> {
+    name.split(|char: char| !char.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+}
+
+fn capitalized(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn identifier(name: String) -> String {
+    match name.chars().next() {
+        Some(first) if first.is_alphabetic() => name,
+        _ => format!("_{}", name),
+    }
+}
+
+fn pascal_case(name: &str) -> String {
+    identifier(words(name).map(capitalized).collect())
+}
+
+fn camel_case(name: &str) -> String {
+    let mut words = words(name);
+    let first = words.next().unwrap_or_default().to_string();
+    identifier(first + &words.map(capitalized).collect::<String>())
+}
+
+fn snake_case(name: &str) -> String {
+    let words = words(name)
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>();
+    identifier(words.join("_"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::remote_config::ParameterGroup;
+    use std::collections::HashMap;
+
+    #[test]
+    fn groups_generated_as_namespaces() {
+        let mut config = RemoteConfig::default();
+        config
+            .parameters
+            .insert("upload_logs".to_string(), Parameter::stub());
+        let mut parameter = Parameter::stub();
+        parameter.value_type = ParameterValueType::Number;
+        parameter.description = None;
+        let group = ParameterGroup {
+            description: Some("Checkout screen".to_string()),
+            parameters: HashMap::from([("max_items".to_string(), parameter)]),
+        };
+        config
+            .parameter_groups
+            .insert("New checkout".to_string(), group);
+
+        let kotlin = generate(Language::Kotlin, "Project", &config).unwrap();
+        assert!(kotlin.contains(
+            "object RemoteConfigKeys {\n    /**\n     * desc\n     */\n    val uploadLogs = RemoteConfigKey<Boolean>(\"upload_logs\")\n"
+        ));
+        assert!(kotlin.contains(
+            "    object NewCheckout {\n        val maxItems = RemoteConfigKey<Double>(\"max_items\")\n    }\n}\n"
+        ));
+
+        let rust = generate(Language::Rust, "Project", &config).unwrap();
+        assert!(rust
+            .contains("    pub mod new_checkout {\n        use super::super::RemoteConfigKey;\n"));
+        assert!(rust.contains(
+            "pub const MAX_ITEMS: RemoteConfigKey<f64> = RemoteConfigKey::new(\"max_items\");"
+        ));
+    }
+
+    #[test]
+    fn keywords_are_escaped() {
+        let mut config = RemoteConfig::default();
+        config
+            .parameters
+            .insert("default".to_string(), Parameter::stub());
+        let group = ParameterGroup {
+            description: None,
+            parameters: HashMap::new(),
+        };
+        config.parameter_groups.insert("type".to_string(), group);
+
+        let swift = generate(Language::Swift, "Project", &config).unwrap();
+        assert!(swift.contains("static let `default` = RemoteConfigKey<Bool>(name: \"default\")"));
+        let typescript = generate(Language::Typescript, "Project", &config).unwrap();
+        assert!(typescript.contains("default_: new RemoteConfigKey<boolean>(\"default\"),"));
+        let rust = generate(Language::Rust, "Project", &config).unwrap();
+        assert!(rust.contains("pub mod r#type {"));
+    }
+
+    #[test]
+    fn identifier_collision_is_error() {
+        let mut config = RemoteConfig::default();
+        config
+            .parameters
+            .insert("max_items".to_string(), Parameter::stub());
+        config
+            .parameters
+            .insert("max-items".to_string(), Parameter::stub());
+
+        let result = generate(Language::Kotlin, "Project", &config);
+        assert_eq!(
+            result.unwrap_err().message,
+            "max-items and max_items have the same identifier maxItems in generated code, rename one of them"
+        );
+    }
+}
@@ -87,9 +87,7 @@ impl ConfigFile {
                         debug!("Error: {:?}", error);
                         Error::new("Failed to create config directory.")
                     })?;
-                    let config = Config {
-                        projects: Vec::new(),
-                    };
+                    let config = Config::default();
                     config.store(file_path.as_path())?;
                     Ok(config)
                 } else {
@@ -1,6 +1,7 @@
 mod fs;
 
-use crate::cli::Config as Subcommand;
+use super::output::{self, Records};
+use crate::cli::{Config as Subcommand, OutputFormat};
 use crate::config::Config;
 use crate::config::Project;
 use crate::error::Result;
@@ -53,8 +54,24 @@ impl ConfigCommand {
                 let config = self.config_file.load()?;
                 let config_path = self.config_file.config_path()?;
                 info!("Config was loaded from {}", config_path);
-                config.render(arguments.project.as_ref());
-                Ok(())
+                let project_name = arguments.project.project.as_ref();
+                if arguments.output == OutputFormat::Table {
+                    config.render(project_name);
+                    return Ok(());
+                }
+                let config = Config {
+                    projects: config
+                        .projects
+                        .into_iter()
+                        .filter(|project| {
+                            project_name
+                                .map(|name| name == &project.name)
+                                .unwrap_or(true)
+                        })
+                        .collect(),
+                    ..config
+                };
+                output::print_structured(&config, || config.records(), arguments.output)
             }
         }
     }
@@ -84,3 +101,17 @@ impl Config {
         println!("{}", table.render());
     }
 }
+
+impl Config {
+    fn records(&self) -> Records {
+        let mut records = Records::new(&["name", "project_number", "app_ids"]);
+        for project in self.projects.iter() {
+            records.push(vec![
+                project.name.clone(),
+                project.project_number().to_string(),
+                project.app_ids.join(" "),
+            ]);
+        }
+        records
+    }
+}
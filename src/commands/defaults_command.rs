@@ -0,0 +1,95 @@
+use crate::cli::DefaultsFormat;
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::defaults;
+use crate::error::{Error, Result};
+use crate::network::NetworkService;
+use crate::remote_config::RemoteConfig;
+use async_trait::async_trait;
+use color_eyre::owo_colors::OwoColorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+
+pub struct DefaultsCommand<NS: NetworkService> {
+    format: DefaultsFormat,
+    out: Option<PathBuf>,
+    network_service: NS,
+}
+
+impl<NS: NetworkService> DefaultsCommand<NS> {
+    pub fn new(format: DefaultsFormat, out: Option<PathBuf>, network_service: NS) -> Self {
+        Self {
+            format,
+            out,
+            network_service,
+        }
+    }
+
+    /// Generates defaults from template file instead of fetching it.
+    pub fn run_for_template(self, path: &Path) -> Result<()> {
+        let content = fs::read_to_string(path).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error {
+                message: format!("Failed to read template at {:?}.", path),
+            }
+        })?;
+        let remote_config = serde_json::from_str::<RemoteConfig>(&content).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error {
+                message: format!("Failed to parse template at {:?}.", path),
+            }
+        })?;
+        self.write(&remote_config)
+    }
+
+    async fn run(mut self, project: &Project) -> Result<()> {
+        info!("Running for {} project", &project.name);
+        let response = self.network_service.get_remote_config(project).await?;
+        self.write(&response.data)
+    }
+
+    fn write(&self, remote_config: &RemoteConfig) -> Result<()> {
+        let (defaults, in_app_defaults) = defaults::collect_defaults(remote_config);
+        if !in_app_defaults.is_empty() {
+            let message = format!(
+                "Parameters {} use in-app default and were skipped, keep their values in the app",
+                in_app_defaults.join(", ")
+            );
+            warn!("{}", message.yellow());
+        }
+        let content = defaults::render(self.format, &defaults);
+        match self.out.as_ref() {
+            None => print!("{}", content),
+            Some(out) => {
+                fs::write(out, content).map_err(|error| {
+                    debug!("Error: {:?}", error);
+                    Error {
+                        message: format!("Failed to write {:?}.", out),
+                    }
+                })?;
+                info!("Defaults were written to {:?}", out);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send> Command for DefaultsCommand<NS> {
+    async fn run_for_single_project(self, project: &Project) -> Result<()> {
+        self.run(project).await
+    }
+
+    async fn run_for_multiple_projects(self, projects: &[Project]) -> Result<()> {
+        let project = &projects[0];
+        if projects.len() > 1 {
+            let message = format!(
+                "Defaults are generated from {} project, pass --project to choose another one",
+                &project.name
+            );
+            warn!("{}", message);
+        }
+        self.run(project).await
+    }
+}
@@ -3,63 +3,289 @@ use crate::config::Project;
 use crate::editor::Editor;
 use crate::error::Result;
 use crate::io::InputReader;
-use crate::network::NetworkService;
+use crate::network::{NetworkService, ResponseWithEtag};
+use crate::pattern::NamePattern;
+use crate::remote_config::{Parameter, RemoteConfig};
 use async_trait::async_trait;
 use color_eyre::owo_colors::OwoColorize;
-use tracing::{error, info, warn};
+use term_table::row::Row;
+use term_table::table_cell::{Alignment, TableCell};
+use term_table::{Table, TableStyle};
+use tracing::{info, warn};
 
 pub struct DeleteCommand<NS: NetworkService, E: Editor> {
-    name: String,
+    names: Vec<NamePattern>,
+    group: Option<String>,
     network_service: NS,
     input_reader: InputReader<E>,
 }
 
+/// Parameter removed from the config with the name of the group it belonged to.
+struct DeletedParameter {
+    name: String,
+    group: Option<String>,
+    parameter: Parameter,
+}
+
 impl<NS: NetworkService, E: Editor> DeleteCommand<NS, E> {
-    pub fn new(name: String, network_service: NS, input_reader: InputReader<E>) -> Self {
+    pub fn new(
+        names: Vec<NamePattern>,
+        group: Option<String>,
+        network_service: NS,
+        input_reader: InputReader<E>,
+    ) -> Self {
         Self {
-            name,
+            names,
+            group,
             network_service,
             input_reader,
         }
     }
 
-    async fn run(&mut self, project: &Project) -> Result<()> {
-        info!("Running for {} project", &project.name);
-        let mut response = self.network_service.get_remote_config(project).await?;
-        let remote_config = &mut response.data;
-        let map_with_parameter = remote_config.get_map_for_existing_parameter(&self.name);
-
-        if map_with_parameter.is_none() {
-            let message = format!("Parameter with name {} does not exists!", &self.name);
-            warn!("{}", message.yellow());
+    async fn run(&mut self, projects: &[Project]) -> Result<()> {
+        let mut changes = Vec::with_capacity(projects.len());
+        for project in projects {
+            info!("Running for {} project", &project.name);
+            let mut response = self.network_service.get_remote_config(project).await?;
+            let (deleted_parameters, deleted_groups) =
+                self.delete_matching_parameters(&mut response.data);
+            if deleted_parameters.is_empty() {
+                let message = format!("No matching parameters in {} project", &project.name);
+                warn!("{}", message.yellow());
+                continue;
+            }
+            changes.push((project, response, deleted_parameters, deleted_groups));
+        }
+        if changes.is_empty() {
             return Ok(());
         }
-        let parameter = map_with_parameter.unwrap().remove(&self.name).unwrap();
 
-        parameter.preview(&self.name, "Parameter will be deleted", None);
+        let mut table = Table::new();
+        table.style = TableStyle::simple();
+        table.max_column_width = 25;
+        for (project, _, deleted_parameters, deleted_groups) in changes.iter() {
+            Self::add_preview_rows(
+                &mut table,
+                &project.name,
+                deleted_parameters,
+                deleted_groups,
+            );
+        }
+        println!("{}", table.render());
         if !self.input_reader.ask_confirmation("Confirm: [Y,n]") {
             warn!("Operation was canceled.");
             return Ok(());
         }
-        self.network_service
-            .update_remote_config(project, response.data, response.etag)
-            .await?;
+        for (project, response, _, _) in changes {
+            let ResponseWithEtag { etag, data } = response;
+            info!("Publishing {} project", &project.name);
+            self.network_service
+                .update_remote_config(project, data, etag)
+                .await?;
+        }
         Ok(())
     }
+
+    /// Returns deleted parameters and names of groups which were removed because they became empty.
+    fn delete_matching_parameters(
+        &self,
+        remote_config: &mut RemoteConfig,
+    ) -> (Vec<DeletedParameter>, Vec<String>) {
+        let is_name_matched = |name: &str| self.names.iter().any(|pattern| pattern.is_match(name));
+        let mut deleted_parameters = Vec::new();
+
+        let names = remote_config
+            .parameters
+            .keys()
+            .filter(|name| is_name_matched(name))
+            .cloned()
+            .collect::<Vec<_>>();
+        for name in names {
+            let parameter = remote_config.parameters.remove(&name).unwrap();
+            deleted_parameters.push(DeletedParameter {
+                name,
+                group: None,
+                parameter,
+            });
+        }
+
+        for (group_name, group) in remote_config.parameter_groups.iter_mut() {
+            let is_group_matched = self.group.as_ref() == Some(group_name);
+            let names = group
+                .parameters
+                .keys()
+                .filter(|name| is_group_matched || is_name_matched(name))
+                .cloned()
+                .collect::<Vec<_>>();
+            for name in names {
+                let parameter = group.parameters.remove(&name).unwrap();
+                deleted_parameters.push(DeletedParameter {
+                    name,
+                    group: Some(group_name.clone()),
+                    parameter,
+                });
+            }
+        }
+        let mut deleted_groups = deleted_parameters
+            .iter()
+            .filter_map(|deleted| deleted.group.clone())
+            .collect::<Vec<_>>();
+        deleted_groups.sort();
+        deleted_groups.dedup();
+        deleted_groups.retain(|group_name| {
+            let is_empty = remote_config
+                .parameter_groups
+                .get(group_name)
+                .map(|group| group.parameters.is_empty())
+                .unwrap_or(false);
+            if is_empty {
+                remote_config.parameter_groups.remove(group_name);
+            }
+            is_empty
+        });
+
+        deleted_parameters
+            .sort_by(|lhs, rhs| (&lhs.group, &lhs.name).cmp(&(&rhs.group, &rhs.name)));
+        (deleted_parameters, deleted_groups)
+    }
+
+    fn add_preview_rows<'a>(
+        table: &mut Table<'a>,
+        project_name: &str,
+        parameters: &'a [DeletedParameter],
+        groups: &[String],
+    ) {
+        let title = format!(
+            "{} parameter(s) will be deleted from {} project",
+            parameters.len(),
+            project_name
+        );
+        table.add_row(Row::new(vec![TableCell::new_with_alignment(
+            title,
+            5,
+            Alignment::Center,
+        )]));
+        parameters
+            .iter()
+            .flat_map(|deleted| {
+                deleted
+                    .parameter
+                    .make_row(&deleted.name, deleted.group.as_deref())
+            })
+            .for_each(|row| table.add_row(row));
+        for group_name in groups {
+            let message = format!("Empty {} group will be deleted", group_name);
+            table.add_row(Row::new(vec![TableCell::new_with_alignment(
+                message.yellow(),
+                5,
+                Alignment::Center,
+            )]));
+        }
+    }
 }
 
 #[async_trait]
 impl<NS: NetworkService + Send, E: Editor + Send> Command for DeleteCommand<NS, E> {
     async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
-        self.run(project).await
+        self.run(std::slice::from_ref(project)).await
     }
 
     async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
-        for project in projects {
-            if let Err(error) = self.run(project).await {
-                error!("{}", error.red());
-            }
+        self.run(projects).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::remote_config::ParameterGroup;
+    use crate::{editor::MockEditor, network::MockNetworkService};
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn matching_parameters_deleted_with_single_publish() {
+        let project = Project::new("Project".to_string(), "123".to_string(), vec![]);
+        let mut config = RemoteConfig::default();
+        for name in ["checkout_v1", "checkout_v2", "upload_logs"] {
+            config
+                .parameters
+                .insert(name.to_string(), Parameter::stub());
         }
-        Ok(())
+        let mut group = ParameterGroup {
+            description: None,
+            parameters: HashMap::new(),
+        };
+        group
+            .parameters
+            .insert("legacy_flag".to_string(), Parameter::stub());
+        config.parameter_groups.insert("Legacy".to_string(), group);
+
+        let mut network_mock = MockNetworkService::new();
+        network_mock
+            .expect_get_remote_config()
+            .times(1)
+            .returning(move |_| {
+                std::result::Result::Ok(ResponseWithEtag {
+                    etag: "e_tag".to_string(),
+                    data: config.clone(),
+                })
+            });
+        network_mock
+            .expect_update_remote_config()
+            .times(1)
+            .withf(|_, config, _| {
+                config.parameters.len() == 1
+                    && config.parameters.contains_key("upload_logs")
+                    && config.parameter_groups.is_empty()
+            })
+            .returning(|_, _, _| std::result::Result::Ok(()));
+        let mut editor_mock = MockEditor::new();
+        editor_mock
+            .expect_read_line()
+            .times(1)
+            .returning(|| Result::Ok("y".to_string()));
+
+        let command = DeleteCommand::new(
+            vec![NamePattern::from_str("checkout_*").unwrap()],
+            Some("Legacy".to_string()),
+            network_mock,
+            InputReader::new(editor_mock),
+        );
+
+        assert!(command.run_for_single_project(&project).await.is_ok());
+    }
+
+    #[test]
+    fn groups_emptied_by_pattern_are_reported() {
+        let mut config = RemoteConfig::default();
+        let group = ParameterGroup {
+            description: Some("Old checkout".to_string()),
+            parameters: HashMap::from([("checkout_v1".to_string(), Parameter::stub())]),
+        };
+        config
+            .parameter_groups
+            .insert("Checkout".to_string(), group);
+        let group = ParameterGroup {
+            description: None,
+            parameters: HashMap::from([
+                ("checkout_v2".to_string(), Parameter::stub()),
+                ("upload_logs".to_string(), Parameter::stub()),
+            ]),
+        };
+        config.parameter_groups.insert("Mixed".to_string(), group);
+        let command = DeleteCommand::new(
+            vec![NamePattern::from_str("checkout_*").unwrap()],
+            None,
+            MockNetworkService::new(),
+            InputReader::new(MockEditor::new()),
+        );
+
+        let (deleted_parameters, deleted_groups) = command.delete_matching_parameters(&mut config);
+
+        assert_eq!(deleted_parameters.len(), 2);
+        assert_eq!(deleted_groups, vec!["Checkout".to_string()]);
+        assert!(config.parameter_groups.contains_key("Mixed"));
+        assert!(!config.parameter_groups.contains_key("Checkout"));
     }
 }
@@ -0,0 +1,534 @@
+use super::add_command::parameter_builder;
+use crate::cli::EditFormat;
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::diff::{Change, GroupedParameter};
+use crate::editor::Editor;
+use crate::error::{Error, Result};
+use crate::io::InputReader;
+use crate::network::NetworkService;
+use crate::remote_config::RemoteConfig;
+use crate::schema::Schemas;
+use async_trait::async_trait;
+use color_eyre::owo_colors::OwoColorize;
+use serde::Serialize;
+use serde_json::Value;
+use std::fs::{self, OpenOptions};
+use std::io::ErrorKind::AlreadyExists;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{debug, error, info, warn};
+
+/// Maximum number of compared lines pairs, larger changes are shown as full replacement.
+const MAX_DIFF_SIZE: usize = 4_000_000;
+/// Number of unchanged lines shown around changed ones.
+const DIFF_CONTEXT: usize = 2;
+/// Number of attempts to pick a temporary file name which is not taken yet.
+const TEMP_FILE_ATTEMPTS: u32 = 100;
+
+pub struct EditCommand<NS: NetworkService, E: Editor> {
+    name: Option<String>,
+    format: EditFormat,
+    schemas: Schemas,
+    network_service: NS,
+    input_reader: InputReader<E>,
+}
+
+impl<NS: NetworkService, E: Editor> EditCommand<NS, E> {
+    pub fn new(
+        name: Option<String>,
+        format: EditFormat,
+        schemas: Schemas,
+        network_service: NS,
+        input_reader: InputReader<E>,
+    ) -> Self {
+        Self {
+            name,
+            format,
+            schemas,
+            network_service,
+            input_reader,
+        }
+    }
+
+    async fn run(&mut self, project: &Project) -> Result<()> {
+        info!("Running for {} project", &project.name);
+        let response = self.network_service.get_remote_config(project).await?;
+        let original = &response.data;
+        let original_text = match self.name.as_ref() {
+            None => self.format.render(original)?,
+            Some(name) => match original.grouped_parameter(name) {
+                Some(parameter) => self.format.render(&parameter)?,
+                None => {
+                    let message = format!("Parameter with name {} does not exists!", name);
+                    warn!("{}", message.yellow());
+                    return Ok(());
+                }
+            },
+        };
+
+        let path = create_temp_file(self.format.extension())?;
+        let mut text = original_text.clone();
+        let mut annotation: Option<String> = None;
+        let (edited, edited_text) = loop {
+            let content = match annotation.as_ref() {
+                None => text.clone(),
+                Some(message) => self.format.annotate(message, &text),
+            };
+            fs::write(&path, content)?;
+            let result =
+                open_editor(&path).and_then(|_| fs::read_to_string(&path).map_err(Error::from));
+            let content = match result {
+                Ok(content) => content,
+                Err(error) => {
+                    let _ = fs::remove_file(&path);
+                    return Err(error);
+                }
+            };
+            text = self.format.strip_annotation(&content);
+            if text.trim().is_empty() {
+                let _ = fs::remove_file(&path);
+                warn!("Operation was canceled.");
+                return Ok(());
+            }
+            match self.apply(original, &text) {
+                Ok(edited) => break edited,
+                Err(error) => annotation = Some(error.message),
+            }
+        };
+        let _ = fs::remove_file(&path);
+
+        // Texts are compared because the diff ignores condition order and group descriptions
+        if edited_text == original_text {
+            info!("No changes were made");
+            return Ok(());
+        }
+        println!("Changes to {} project:", &project.name);
+        print_diff(&original_text, &edited_text);
+        if !self.input_reader.ask_confirmation("Confirm: [Y,n]") {
+            warn!("Operation was canceled.");
+            return Ok(());
+        }
+        self.network_service
+            .update_remote_config(project, edited, response.etag)
+            .await?;
+        Ok(())
+    }
+
+    /// Applies edited text to the original config, returns validated config and its normalized text.
+    fn apply(&self, original: &RemoteConfig, text: &str) -> Result<(RemoteConfig, String)> {
+        let value = self.format.parse(text)?;
+        let (edited, edited_text) = match self.name.as_ref() {
+            None => {
+                let edited =
+                    serde_json::from_value::<RemoteConfig>(value).map_err(|error| Error {
+                        message: format!("Invalid template: {}", error),
+                    })?;
+                let edited_text = self.format.render(&edited)?;
+                (edited, edited_text)
+            }
+            Some(name) => {
+                let parameter =
+                    serde_json::from_value::<GroupedParameter>(value).map_err(|error| Error {
+                        message: format!("Invalid parameter: {}", error),
+                    })?;
+                let edited_text = self.format.render(&parameter)?;
+                let mut edited = original.clone();
+                edited.replace_parameter(name, parameter);
+                (edited, edited_text)
+            }
+        };
+        let changes = original.diff(&edited);
+        edited.validate(&changes, &self.schemas)?;
+        Ok((edited, edited_text))
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send, E: Editor + Send> Command for EditCommand<NS, E> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        self.run(project).await
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        for project in projects {
+            if let Err(error) = self.run(project).await {
+                error!("{}", error.red());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl EditFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            EditFormat::Json => "json",
+            EditFormat::Yaml => "yaml",
+        }
+    }
+
+    fn comment_prefix(&self) -> &'static str {
+        match self {
+            EditFormat::Json => "//",
+            EditFormat::Yaml => "#",
+        }
+    }
+
+    /// Renders value with sorted keys, so unchanged values produce the same text.
+    pub fn render<T: Serialize>(&self, value: &T) -> Result<String> {
+        let value = serde_json::to_value(value).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error::new("Failed to serialize remote config.")
+        })?;
+        let text = match self {
+            EditFormat::Json => serde_json::to_string_pretty(&value)
+                .map(|text| text + "\n")
+                .map_err(|error| error.to_string()),
+            EditFormat::Yaml => serde_yaml::to_string(&value).map_err(|error| error.to_string()),
+        };
+        text.map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error::new("Failed to serialize remote config.")
+        })
+    }
+
+    fn parse(&self, text: &str) -> Result<Value> {
+        let value = match self {
+            EditFormat::Json => serde_json::from_str(text).map_err(|error| error.to_string()),
+            EditFormat::Yaml => serde_yaml::from_str(text).map_err(|error| error.to_string()),
+        };
+        value.map_err(|error| Error {
+            message: format!("Failed to parse: {}", error),
+        })
+    }
+
+    /// Prepends error message as comment lines, they are removed before parsing.
+    fn annotate(&self, message: &str, text: &str) -> String {
+        let prefix = self.comment_prefix();
+        let mut lines = vec![format!(
+            "{} Edit was rejected, fix the error or remove all content to cancel:",
+            prefix
+        )];
+        lines.extend(message.lines().map(|line| format!("{} {}", prefix, line)));
+        lines.join("\n") + "\n" + text
+    }
+
+    fn strip_annotation(&self, content: &str) -> String {
+        let prefix = self.comment_prefix();
+        let mut lines = content.lines().peekable();
+        while lines.next_if(|line| line.starts_with(prefix)).is_some() {}
+        lines.map(|line| line.to_string() + "\n").collect()
+    }
+}
+
+impl RemoteConfig {
+    /// Moves edited parameter to its group, other groups left empty are removed.
+    fn replace_parameter(&mut self, name: &str, parameter: GroupedParameter) {
+        self.parameters.remove(name);
+        let mut previous_group = None;
+        for (group_name, group) in self.parameter_groups.iter_mut() {
+            if group.parameters.remove(name).is_some() {
+                previous_group = Some(group_name.clone());
+            }
+        }
+        // Target group is kept even if it's empty for now, so its description is not lost
+        if let Some(group_name) =
+            previous_group.filter(|name| Some(name) != parameter.group.as_ref())
+        {
+            if self.parameter_groups[&group_name].parameters.is_empty() {
+                self.parameter_groups.remove(&group_name);
+            }
+        }
+        self.put_parameter(name.to_string(), parameter.parameter, parameter.group);
+    }
+
+    /// Validates changed parameters and parameters using deleted conditions,
+    /// so values which were already published don't block saving.
+    fn validate(&self, changes: &[Change], schemas: &Schemas) -> Result<()> {
+        let changed_names = changes
+            .iter()
+            .filter(|change| change.is_parameter())
+            .map(Change::name)
+            .collect::<Vec<_>>();
+        let deleted_conditions = changes
+            .iter()
+            .filter_map(|change| match change {
+                Change::Condition {
+                    name, after: None, ..
+                } => Some(name),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let parameters = self
+            .parameters
+            .iter()
+            .chain(
+                self.parameter_groups
+                    .values()
+                    .flat_map(|group| group.parameters.iter()),
+            )
+            .filter(|(name, parameter)| {
+                changed_names.contains(&name.as_str())
+                    || deleted_conditions
+                        .iter()
+                        .any(|condition| parameter.conditional_values.contains_key(*condition))
+            });
+        for (name, parameter) in parameters {
+            parameter_builder::validate_parameter(name, parameter, &self.conditions)?;
+            schemas.check(name, parameter)?;
+        }
+        Ok(())
+    }
+}
+
+/// Creates an empty temporary file with a new name, existing files and symlinks are never opened.
+fn create_temp_file(extension: &str) -> Result<PathBuf> {
+    let directory = std::env::temp_dir();
+    for attempt in 0..TEMP_FILE_ATTEMPTS {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.subsec_nanos())
+            .unwrap_or_default();
+        let path = directory.join(format!(
+            "rcm-edit-{}-{}-{}.{}",
+            std::process::id(),
+            nanos,
+            attempt,
+            extension
+        ));
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        match options.open(&path) {
+            Ok(_) => return Ok(path),
+            Err(error) if error.kind() == AlreadyExists => continue,
+            Err(error) => {
+                debug!("Error: {:?}", error);
+                return Err(Error::new("Failed to create temporary file."));
+            }
+        }
+    }
+    Err(Error::new("Failed to create temporary file."))
+}
+
+fn open_editor(path: &Path) -> Result<()> {
+    let default_editor = if cfg!(target_os = "windows") {
+        "notepad"
+    } else {
+        "vi"
+    };
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| default_editor.to_string());
+    let mut command = if cfg!(target_os = "windows") {
+        let mut command = std::process::Command::new("cmd");
+        command.args(["/C", &format!("{} \"{}\"", editor, path.display())]);
+        command
+    } else {
+        let mut command = std::process::Command::new("sh");
+        command
+            .args(["-c", &format!("{} \"$1\"", editor), "--"])
+            .arg(path);
+        command
+    };
+    let status = command
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::inherit())
+        .status()?;
+    if !status.success() {
+        return Err(Error {
+            message: format!("Editor {} exited with {}", editor, status),
+        });
+    }
+    Ok(())
+}
+
+/// Prints changed lines with a few unchanged lines around them.
+pub fn print_diff(before: &str, after: &str) {
+    let before = before.lines().collect::<Vec<_>>();
+    let after = after.lines().collect::<Vec<_>>();
+    let lines = diff_lines(&before, &after);
+    let is_visible = |index: usize| {
+        let start = index.saturating_sub(DIFF_CONTEXT);
+        let end = (index + DIFF_CONTEXT + 1).min(lines.len());
+        lines[start..end].iter().any(|(marker, _)| *marker != ' ')
+    };
+    let mut is_skipped = false;
+    for (index, (marker, line)) in lines.iter().enumerate() {
+        if !is_visible(index) {
+            if !is_skipped {
+                println!("{}", "...".dimmed());
+            }
+            is_skipped = true;
+            continue;
+        }
+        is_skipped = false;
+        let line = format!("{} {}", marker, line);
+        match marker {
+            '-' => println!("{}", line.red()),
+            '+' => println!("{}", line.green()),
+            _ => println!("{}", line),
+        }
+    }
+}
+
+/// Line diff based on the longest common subsequence, markers are `' '`, `'-'` and `'+'`.
+fn diff_lines<'a>(before: &[&'a str], after: &[&'a str]) -> Vec<(char, &'a str)> {
+    let prefix = before
+        .iter()
+        .zip(after.iter())
+        .take_while(|(lhs, rhs)| lhs == rhs)
+        .count();
+    let suffix = before[prefix..]
+        .iter()
+        .rev()
+        .zip(after[prefix..].iter().rev())
+        .take_while(|(lhs, rhs)| lhs == rhs)
+        .count();
+    let removed = &before[prefix..before.len() - suffix];
+    let added = &after[prefix..after.len() - suffix];
+
+    let mut lines = before[..prefix]
+        .iter()
+        .map(|line| (' ', *line))
+        .collect::<Vec<_>>();
+    if removed.len() * added.len() > MAX_DIFF_SIZE {
+        lines.extend(removed.iter().map(|line| ('-', *line)));
+        lines.extend(added.iter().map(|line| ('+', *line)));
+    } else {
+        let mut lengths = vec![vec![0usize; added.len() + 1]; removed.len() + 1];
+        for i in (0..removed.len()).rev() {
+            for j in (0..added.len()).rev() {
+                lengths[i][j] = if removed[i] == added[j] {
+                    lengths[i + 1][j + 1] + 1
+                } else {
+                    lengths[i + 1][j].max(lengths[i][j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < removed.len() || j < added.len() {
+            if i < removed.len() && j < added.len() && removed[i] == added[j] {
+                lines.push((' ', removed[i]));
+                i += 1;
+                j += 1;
+            } else if i < removed.len()
+                && (j == added.len() || lengths[i + 1][j] >= lengths[i][j + 1])
+            {
+                lines.push(('-', removed[i]));
+                i += 1;
+            } else {
+                lines.push(('+', added[j]));
+                j += 1;
+            }
+        }
+    }
+    lines.extend(
+        after[after.len() - suffix..]
+            .iter()
+            .map(|line| (' ', *line)),
+    );
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::remote_config::{Parameter, ParameterGroup, ParameterValueType};
+    use std::collections::HashMap;
+
+    #[test]
+    fn rejected_edit_keeps_text_without_annotation() {
+        let format = EditFormat::Yaml;
+        let text = "group: Logs\nvalueType: BOOLEAN\n";
+
+        let annotated = format.annotate("Failed to parse:\nline 2", text);
+
+        assert!(annotated.starts_with("# Edit was rejected"));
+        assert!(annotated.contains("# line 2\ngroup: Logs"));
+        assert_eq!(format.strip_annotation(&annotated), text);
+    }
+
+    #[test]
+    fn diff_lines_marks_changed_lines() {
+        let before = ["{", "  \"a\": 1,", "  \"b\": 2", "}"];
+        let after = ["{", "  \"a\": 1,", "  \"b\": 3,", "  \"c\": 4", "}"];
+
+        let lines = diff_lines(&before, &after);
+
+        assert_eq!(
+            lines,
+            vec![
+                (' ', "{"),
+                (' ', "  \"a\": 1,"),
+                ('-', "  \"b\": 2"),
+                ('+', "  \"b\": 3,"),
+                ('+', "  \"c\": 4"),
+                (' ', "}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn only_changed_parameters_are_validated() {
+        let mut original = RemoteConfig::default();
+        let mut legacy = Parameter::stub();
+        legacy.value_type = ParameterValueType::Unspecified;
+        original.parameters.insert("legacy".to_string(), legacy);
+        let mut edited = original.clone();
+        edited
+            .parameters
+            .insert("upload_logs".to_string(), Parameter::stub());
+
+        let changes = original.diff(&edited);
+        assert!(edited.validate(&changes, &Schemas::default()).is_ok());
+
+        let mut invalid = Parameter::stub();
+        invalid.value_type = ParameterValueType::Unspecified;
+        edited.parameters.insert("upload_logs".to_string(), invalid);
+        let changes = original.diff(&edited);
+        assert_eq!(
+            edited
+                .validate(&changes, &Schemas::default())
+                .unwrap_err()
+                .message,
+            "Value type of upload_logs parameter must be specified."
+        );
+    }
+
+    #[test]
+    fn editing_single_grouped_parameter_keeps_group_description() {
+        let mut config = RemoteConfig::default();
+        let group = ParameterGroup {
+            description: Some("Logging".to_string()),
+            parameters: HashMap::from([("upload_logs".to_string(), Parameter::stub())]),
+        };
+        config.parameter_groups.insert("Logs".to_string(), group);
+        let mut parameter = Parameter::stub();
+        parameter.description = Some("Uploads logs".to_string());
+
+        config.replace_parameter(
+            "upload_logs",
+            GroupedParameter {
+                group: Some("Logs".to_string()),
+                parameter: parameter.clone(),
+            },
+        );
+
+        let group = &config.parameter_groups["Logs"];
+        assert_eq!(group.description, Some("Logging".to_string()));
+        assert_eq!(group.parameters["upload_logs"], parameter);
+    }
+
+    #[test]
+    fn temp_file_is_created_with_new_name() {
+        let first = create_temp_file("json").unwrap();
+        let second = create_temp_file("json").unwrap();
+        let _ = fs::remove_file(&first);
+        let _ = fs::remove_file(&second);
+        assert_ne!(first, second);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::cli::FlagAction;
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::editor::Editor;
+use crate::error::{Error, Result};
+use crate::io::InputReader;
+use crate::network::NetworkService;
+use crate::remote_config::{Parameter, ParameterValue, ParameterValueType};
+use async_trait::async_trait;
+use color_eyre::owo_colors::OwoColorize;
+use tracing::{error, info, warn};
+
+pub struct FlagCommand<NS: NetworkService, E: Editor> {
+    action: FlagAction,
+    name: String,
+    condition: Option<String>,
+    network_service: NS,
+    input_reader: InputReader<E>,
+}
+
+impl<NS: NetworkService, E: Editor> FlagCommand<NS, E> {
+    pub fn new(
+        action: FlagAction,
+        name: String,
+        condition: Option<String>,
+        network_service: NS,
+        input_reader: InputReader<E>,
+    ) -> Self {
+        Self {
+            action,
+            name,
+            condition,
+            network_service,
+            input_reader,
+        }
+    }
+
+    async fn run(&mut self, project: &Project) -> Result<()> {
+        info!("Running for {} project", &project.name);
+        let mut response = self.network_service.get_remote_config(project).await?;
+        let remote_config = &mut response.data;
+
+        if let Some(condition) = self.condition.as_ref() {
+            let is_existing = remote_config
+                .conditions
+                .iter()
+                .any(|existing| &existing.name == condition);
+            if !is_existing {
+                return Err(Error {
+                    message: format!("Condition {} does not exist", condition),
+                });
+            }
+        }
+        let group_name = match remote_config.find_parameter(&self.name) {
+            None => {
+                let message = format!("Parameter with name {} does not exists!", &self.name);
+                warn!("{}", message.yellow());
+                return Ok(());
+            }
+            Some((group_name, _)) => group_name.map(String::from),
+        };
+        let parameter = remote_config
+            .get_map_for_existing_parameter(&self.name)
+            .and_then(|map| map.get_mut(&self.name))
+            .unwrap();
+        let previous = parameter.clone();
+        self.action.apply(parameter, self.condition.as_deref())?;
+        if parameter == &previous {
+            info!("Parameter {} already has this value", &self.name);
+            return Ok(());
+        }
+
+        previous.preview(&self.name, "Current value", group_name.as_deref());
+        parameter.preview(&self.name, "New value", group_name.as_deref());
+        if !self.input_reader.ask_confirmation("Confirm: [Y,n]") {
+            warn!("Operation was canceled.");
+            return Ok(());
+        }
+        self.network_service
+            .update_remote_config(project, response.data, response.etag)
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send, E: Editor + Send> Command for FlagCommand<NS, E> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        self.run(project).await
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        for project in projects {
+            if let Err(error) = self.run(project).await {
+                error!("{}", error.red());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FlagAction {
+    /// Sets default value or value for the condition of boolean parameter.
+    fn apply(&self, parameter: &mut Parameter, condition: Option<&str>) -> Result<()> {
+        if parameter.value_type != ParameterValueType::Boolean {
+            return Err(Error::new("Only boolean parameters can be switched"));
+        }
+        let value = match condition {
+            Some(condition) => parameter.conditional_values.get(condition),
+            None => parameter.default_value.as_ref(),
+        };
+        let is_enabled = match (self, value) {
+            (FlagAction::On, _) => true,
+            (FlagAction::Off, _) => false,
+            (FlagAction::Toggle, Some(ParameterValue::Value(value))) => value != "true",
+            (FlagAction::Toggle, _) => {
+                return Err(Error::new(
+                    "Only parameters with true or false value can be toggled",
+                ))
+            }
+        };
+        let value = ParameterValue::Value(is_enabled.to_string());
+        match condition {
+            Some(condition) => {
+                parameter
+                    .conditional_values
+                    .insert(condition.to_string(), value);
+            }
+            None => parameter.default_value = Some(value),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle_switches_conditional_value() {
+        let mut parameter = Parameter::stub();
+        parameter
+            .conditional_values
+            .insert("iOS".to_string(), ParameterValue::Value("true".to_string()));
+
+        FlagAction::Toggle
+            .apply(&mut parameter, Some("iOS"))
+            .unwrap();
+        assert_eq!(
+            parameter.conditional_values["iOS"],
+            ParameterValue::Value("false".to_string())
+        );
+        assert!(FlagAction::Toggle
+            .apply(&mut parameter, Some("Android"))
+            .is_err());
+
+        FlagAction::On.apply(&mut parameter, None).unwrap();
+        assert_eq!(
+            parameter.default_value,
+            Some(ParameterValue::Value("true".to_string()))
+        );
+
+        parameter.value_type = ParameterValueType::String;
+        assert!(FlagAction::Off.apply(&mut parameter, None).is_err());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::editor::Editor;
+use crate::error::Result;
+use crate::io::{self, InputReader};
+use crate::network::NetworkService;
+use crate::remote_config::{Condition, RemoteConfig};
+use async_trait::async_trait;
+use color_eyre::owo_colors::OwoColorize;
+use std::collections::HashSet;
+use term_table::row::Row;
+use term_table::table_cell::{Alignment, TableCell};
+use term_table::{Table, TableStyle};
+use tracing::{error, info, warn};
+
+pub struct GcCommand<NS: NetworkService, E: Editor> {
+    is_auto_confirmed: bool,
+    network_service: NS,
+    input_reader: InputReader<E>,
+}
+
+impl<NS: NetworkService, E: Editor> GcCommand<NS, E> {
+    pub fn new(is_auto_confirmed: bool, network_service: NS, input_reader: InputReader<E>) -> Self {
+        Self {
+            is_auto_confirmed,
+            network_service,
+            input_reader: input_reader.with_auto_confirmation(is_auto_confirmed),
+        }
+    }
+
+    async fn run(&mut self, project: &Project) -> Result<()> {
+        info!("Running for {} project", &project.name);
+        let mut response = self.network_service.get_remote_config(project).await?;
+        let remote_config = &mut response.data;
+        let unused_conditions = remote_config.unused_conditions();
+        if unused_conditions.is_empty() {
+            info!("No unused conditions in {} project", &project.name);
+            return Ok(());
+        }
+
+        let mut table = Table::new();
+        table.style = TableStyle::simple();
+        table.max_column_width = 25;
+        let title = format!(
+            "{} unused condition(s) in {} project",
+            unused_conditions.len(),
+            &project.name
+        );
+        table.add_row(Row::new(vec![TableCell::new_with_alignment(
+            title,
+            5,
+            Alignment::Center,
+        )]));
+        unused_conditions
+            .iter()
+            .for_each(|condition| table.add_row(condition.make_row()));
+        println!("{}", table.render());
+
+        let mut names = unused_conditions
+            .iter()
+            .map(|condition| condition.name.clone())
+            .collect::<Vec<_>>();
+        if !self.is_auto_confirmed {
+            let labels = names.iter().map(String::as_str).collect::<Vec<_>>();
+            let selected =
+                io::request_select_items_in_list("Select conditions to delete:", &labels, true)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect::<HashSet<_>>();
+            let mut indices = 0..;
+            names.retain(|_| selected.contains(&indices.next().unwrap()));
+        }
+        if names.is_empty() {
+            warn!("Operation was canceled.");
+            return Ok(());
+        }
+
+        let message = format!("Delete {} condition(s)? [Y,n]", names.len());
+        if !self.input_reader.ask_confirmation(&message) {
+            warn!("Operation was canceled.");
+            return Ok(());
+        }
+        remote_config
+            .conditions
+            .retain(|condition| !names.contains(&condition.name));
+        self.network_service
+            .update_remote_config(project, response.data, response.etag)
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send, E: Editor + Send> Command for GcCommand<NS, E> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        self.run(project).await
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        for project in projects {
+            if let Err(error) = self.run(project).await {
+                error!("{}", error.red());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RemoteConfig {
+    /// Returns conditions which are not used by any parameter, including grouped ones.
+    pub fn unused_conditions(&self) -> Vec<&Condition> {
+        let used_names = self
+            .parameters
+            .values()
+            .chain(
+                self.parameter_groups
+                    .values()
+                    .flat_map(|group| group.parameters.values()),
+            )
+            .flat_map(|parameter| parameter.conditional_values.keys())
+            .collect::<HashSet<_>>();
+        self.conditions
+            .iter()
+            .filter(|condition| !used_names.contains(&condition.name))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::remote_config::{Parameter, ParameterGroup, ParameterValue, TagColor};
+    use std::collections::HashMap;
+
+    #[test]
+    fn conditions_used_in_groups_are_kept() {
+        let mut config = RemoteConfig::default();
+        for name in ["iOS", "Android", "Beta"] {
+            config.conditions.push(Condition {
+                name: name.to_string(),
+                expression: "true".to_string(),
+                tag_color: TagColor::Green,
+            });
+        }
+        let mut parameter = Parameter::stub();
+        parameter
+            .conditional_values
+            .insert("iOS".to_string(), ParameterValue::Value("true".to_string()));
+        config
+            .parameters
+            .insert("upload_logs".to_string(), parameter.clone());
+        parameter.conditional_values.clear();
+        parameter.conditional_values.insert(
+            "Beta".to_string(),
+            ParameterValue::Value("true".to_string()),
+        );
+        let group = ParameterGroup {
+            description: None,
+            parameters: HashMap::from([("beta_flag".to_string(), parameter)]),
+        };
+        config.parameter_groups.insert("Beta".to_string(), group);
+
+        let unused_names = config
+            .unused_conditions()
+            .into_iter()
+            .map(|condition| condition.name.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(unused_names, vec!["Android"]);
+    }
+}
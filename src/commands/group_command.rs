@@ -0,0 +1,289 @@
+use crate::cli::Group as Subcommand;
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::editor::Editor;
+use crate::error::{Error, Result};
+use crate::io::{self, InputReader};
+use crate::network::NetworkService;
+use crate::remote_config::{ParameterGroup, RemoteConfig};
+use async_trait::async_trait;
+use color_eyre::owo_colors::OwoColorize;
+use term_table::row::Row;
+use term_table::table_cell::{Alignment, TableCell};
+use term_table::{Table, TableStyle};
+use tracing::{error, info, warn};
+
+pub struct GroupCommand<NS: NetworkService, E: Editor> {
+    subcommand: Subcommand,
+    network_service: NS,
+    input_reader: InputReader<E>,
+}
+
+impl<NS: NetworkService, E: Editor> GroupCommand<NS, E> {
+    pub fn new(subcommand: Subcommand, network_service: NS, input_reader: InputReader<E>) -> Self {
+        Self {
+            subcommand,
+            network_service,
+            input_reader,
+        }
+    }
+
+    async fn run(&mut self, project: &Project) -> Result<()> {
+        info!("Running for {} project", &project.name);
+        let mut response = self.network_service.get_remote_config(project).await?;
+        let config = &mut response.data;
+
+        let is_updated = match &self.subcommand {
+            Subcommand::List(_) => {
+                config.render_groups(&project.name);
+                false
+            }
+            Subcommand::Rename(arguments) => {
+                config.rename_group(&arguments.name, arguments.new_name.clone())?;
+                info!(
+                    "Group {} will be renamed to {}",
+                    &arguments.name, &arguments.new_name
+                );
+                true
+            }
+            Subcommand::Describe(arguments) => {
+                let description = match arguments.description.as_ref() {
+                    Some(description) => description.clone(),
+                    None => self
+                        .input_reader
+                        .request_user_input(&"Enter group description (Optional):".green())?,
+                };
+                let group = config.find_group_mut(&arguments.name)?;
+                group.description = if description.is_empty() {
+                    None
+                } else {
+                    Some(description)
+                };
+                true
+            }
+            Subcommand::Delete(arguments) => {
+                let name = arguments.name.clone();
+                Self::delete_group(&mut self.input_reader, config, &name)?
+            }
+            Subcommand::Merge(arguments) => {
+                let group = config.find_group_mut(&arguments.from)?;
+                let parameters_count = group.parameters.len();
+                config.find_group_mut(&arguments.into)?;
+                let message = format!(
+                    "{} parameters will be moved from {} to {} group. Group {} will be deleted. Confirm: [Y,n]",
+                    parameters_count, &arguments.from, &arguments.into, &arguments.from
+                );
+                if self.input_reader.ask_confirmation(&message) {
+                    config.merge_groups(&arguments.from, &arguments.into)?;
+                    true
+                } else {
+                    warn!("Operation was canceled.");
+                    false
+                }
+            }
+        };
+        if is_updated {
+            self.network_service
+                .update_remote_config(project, response.data, response.etag)
+                .await?;
+        }
+        Ok(())
+    }
+
+    fn delete_group(
+        input_reader: &mut InputReader<E>,
+        config: &mut RemoteConfig,
+        name: &str,
+    ) -> Result<bool> {
+        let group = config.find_group_mut(name)?;
+        if group.parameters.is_empty() {
+            let message = format!("Empty group {} will be deleted. Confirm: [Y,n]", name);
+            if !input_reader.ask_confirmation(&message) {
+                warn!("Operation was canceled.");
+                return Ok(false);
+            }
+            config.parameter_groups.remove(name);
+            return Ok(true);
+        }
+        let other_groups = config
+            .parameter_groups
+            .keys()
+            .filter(|group_name| group_name.as_str() != name)
+            .cloned()
+            .collect::<Vec<_>>();
+        let options = std::iter::once("Move parameters to the root")
+            .chain(other_groups.iter().map(|group_name| group_name.as_str()));
+        let label = format!(
+            "Group {} contains parameters. Select where they should be moved:",
+            name
+        );
+        let index = io::request_select_item_in_list(&label, options, None);
+        let destination = match index {
+            None => {
+                warn!("Operation was canceled.");
+                return Ok(false);
+            }
+            Some(0) => None,
+            Some(index) => Some(other_groups[index - 1].as_str()),
+        };
+        let group = config.parameter_groups.remove(name).unwrap();
+        match destination {
+            None => config.parameters.extend(group.parameters),
+            Some(destination) => config
+                .find_group_mut(destination)?
+                .parameters
+                .extend(group.parameters),
+        }
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send, E: Editor + Send> Command for GroupCommand<NS, E> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        self.run(project).await
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        for project in projects {
+            if let Err(error) = self.run(project).await {
+                error!("{}", error.message.red());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RemoteConfig {
+    fn find_group_mut(&mut self, name: &str) -> Result<&mut ParameterGroup> {
+        self.parameter_groups.get_mut(name).ok_or_else(|| Error {
+            message: format!("Group with name {} does not exist!", name),
+        })
+    }
+
+    fn rename_group(&mut self, name: &str, new_name: String) -> Result<()> {
+        if name == new_name {
+            self.find_group_mut(name)?;
+            return Err(Error {
+                message: format!("Group {} already has this name.", name),
+            });
+        }
+        if self.parameter_groups.contains_key(&new_name) {
+            return Err(Error {
+                message: format!("Group with name {} already exists!", new_name),
+            });
+        }
+        let group = self.parameter_groups.remove(name).ok_or_else(|| Error {
+            message: format!("Group with name {} does not exist!", name),
+        })?;
+        self.parameter_groups.insert(new_name, group);
+        Ok(())
+    }
+
+    fn merge_groups(&mut self, source: &str, destination: &str) -> Result<()> {
+        if source == destination {
+            return Err(Error::new("Group can not be merged into itself."));
+        }
+        self.find_group_mut(destination)?;
+        let group = self.parameter_groups.remove(source).ok_or_else(|| Error {
+            message: format!("Group with name {} does not exist!", source),
+        })?;
+        self.find_group_mut(destination)?
+            .parameters
+            .extend(group.parameters);
+        Ok(())
+    }
+
+    fn render_groups(&self, project_name: &str) {
+        let mut table = Table::new();
+        table.max_column_width = 40;
+        table.style = TableStyle::simple();
+        table.add_row(Row::new(vec![TableCell::new_with_alignment(
+            format!("{} parameter groups", project_name),
+            3,
+            Alignment::Center,
+        )]));
+        table.add_row(Row::new(vec![
+            TableCell::new("Group name"),
+            TableCell::new("Description"),
+            TableCell::new("Parameters"),
+        ]));
+        let mut groups = self.parameter_groups.iter().collect::<Vec<_>>();
+        groups.sort_by_key(|(name, _)| name.as_str());
+        groups
+            .into_iter()
+            .map(|(name, group)| {
+                Row::new(vec![
+                    TableCell::new(name),
+                    TableCell::new(group.description.as_deref().unwrap_or("")),
+                    TableCell::new(group.parameters.len()),
+                ])
+            })
+            .for_each(|row| table.add_row(row));
+        println!("{}", table.render());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::remote_config::{Parameter, ParameterValue, ParameterValueType};
+    use std::collections::HashMap;
+
+    fn group_with_parameter(name: &str) -> ParameterGroup {
+        let parameter = Parameter {
+            default_value: Some(ParameterValue::Value("true".to_string())),
+            conditional_values: HashMap::new(),
+            description: None,
+            value_type: ParameterValueType::Boolean,
+        };
+        let mut parameters = HashMap::new();
+        parameters.insert(name.to_string(), parameter);
+        ParameterGroup {
+            description: None,
+            parameters,
+        }
+    }
+
+    #[test]
+    fn merge_moves_parameters_and_removes_source_group() {
+        let mut parameter_groups = HashMap::new();
+        parameter_groups.insert("old".to_string(), group_with_parameter("first"));
+        parameter_groups.insert("new".to_string(), group_with_parameter("second"));
+        let mut config = RemoteConfig {
+            conditions: Vec::new(),
+            parameters: HashMap::new(),
+            parameter_groups,
+        };
+
+        config.merge_groups("old", "new").unwrap();
+
+        assert!(!config.parameter_groups.contains_key("old"));
+        let group = &config.parameter_groups["new"];
+        assert!(group.parameters.contains_key("first"));
+        assert!(group.parameters.contains_key("second"));
+    }
+
+    #[test]
+    fn rename_fails_when_group_exists() {
+        let mut parameter_groups = HashMap::new();
+        parameter_groups.insert("first".to_string(), group_with_parameter("a"));
+        parameter_groups.insert("second".to_string(), group_with_parameter("b"));
+        let mut config = RemoteConfig {
+            conditions: Vec::new(),
+            parameters: HashMap::new(),
+            parameter_groups,
+        };
+
+        assert!(config.rename_group("first", "second".to_string()).is_err());
+        assert_eq!(
+            config
+                .rename_group("first", "first".to_string())
+                .unwrap_err()
+                .message,
+            "Group first already has this name."
+        );
+        assert!(config.rename_group("first", "third".to_string()).is_ok());
+        assert!(config.parameter_groups.contains_key("third"));
+    }
+}
@@ -0,0 +1,356 @@
+use crate::commands::add_command::parameter_builder;
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::defaults::{self, DefaultValue};
+use crate::editor::Editor;
+use crate::error::{Error, Result};
+use crate::io::{self, InputReader};
+use crate::network::NetworkService;
+use crate::remote_config::{Parameter, ParameterValue, RemoteConfig};
+use crate::schema::Schemas;
+use async_trait::async_trait;
+use color_eyre::owo_colors::OwoColorize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+use term_table::row::Row;
+use term_table::table_cell::{Alignment, TableCell};
+use term_table::{Table, TableStyle};
+use tracing::{debug, error, info, warn};
+
+pub struct ImportDefaultsCommand<NS: NetworkService, E: Editor> {
+    path: PathBuf,
+    group: Option<String>,
+    is_auto_confirmed: bool,
+    schemas: Schemas,
+    network_service: NS,
+    input_reader: InputReader<E>,
+}
+
+/// Local default which does not match the remote template.
+#[derive(Debug, PartialEq)]
+enum Mismatch {
+    Missing,
+    Different(Option<DefaultValue>),
+}
+
+impl<NS: NetworkService, E: Editor> ImportDefaultsCommand<NS, E> {
+    pub fn new(
+        path: PathBuf,
+        group: Option<String>,
+        is_auto_confirmed: bool,
+        schemas: Schemas,
+        network_service: NS,
+        input_reader: InputReader<E>,
+    ) -> Self {
+        Self {
+            path,
+            group,
+            is_auto_confirmed,
+            schemas,
+            network_service,
+            input_reader: input_reader.with_auto_confirmation(is_auto_confirmed),
+        }
+    }
+
+    fn load_defaults(&self) -> Result<BTreeMap<String, DefaultValue>> {
+        let content = fs::read_to_string(&self.path).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error {
+                message: format!("Failed to read defaults at {:?}.", self.path),
+            }
+        })?;
+        let format = defaults::detect_format(&self.path, &content)
+            .ok_or_else(|| Error::new("Unknown defaults format, expected plist, XML or JSON."))?;
+        let defaults = defaults::parse(format, &content)?;
+        if defaults.is_empty() {
+            return Err(Error {
+                message: format!("No default values found in {:?}.", self.path),
+            });
+        }
+        Ok(defaults)
+    }
+
+    async fn run(
+        &mut self,
+        project: &Project,
+        defaults: &BTreeMap<String, DefaultValue>,
+    ) -> Result<()> {
+        info!("Running for {} project", &project.name);
+        let mut response = self.network_service.get_remote_config(project).await?;
+        let remote_config = &mut response.data;
+        let mismatches = remote_config.compare_defaults(defaults);
+        if mismatches.is_empty() {
+            info!("{} project contains all default values", &project.name);
+            return Ok(());
+        }
+        println!(
+            "{}",
+            make_table(&project.name, defaults, &mismatches).render()
+        );
+
+        let mut parameters = mismatches
+            .iter()
+            .filter(|(_, mismatch)| *mismatch == Mismatch::Missing)
+            .filter_map(|(name, _)| {
+                let parameter = new_parameter(&defaults[*name]);
+                let result = parameter_builder::validate_parameter(
+                    name,
+                    &parameter,
+                    &remote_config.conditions,
+                )
+                .and_then(|_| self.schemas.check(name, &parameter));
+                match result {
+                    Ok(_) => Some((name.to_string(), parameter)),
+                    Err(error) => {
+                        let message = format!("Skipping {}: {}", name, error);
+                        warn!("{}", message.yellow());
+                        None
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        if parameters.is_empty() {
+            return Ok(());
+        }
+        if !self.is_auto_confirmed {
+            let labels = parameters
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>();
+            let selected =
+                io::request_select_items_in_list("Select parameters to add:", &labels, true)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect::<HashSet<_>>();
+            let mut indices = 0..;
+            parameters.retain(|_| selected.contains(&indices.next().unwrap()));
+        }
+        if parameters.is_empty() {
+            warn!("Operation was canceled.");
+            return Ok(());
+        }
+
+        for (name, parameter) in parameters.iter() {
+            parameter.preview(name, "Parameter will be added", self.group.as_deref());
+        }
+        let message = format!("Add {} parameter(s)? [Y,n]", parameters.len());
+        if !self.input_reader.ask_confirmation(&message) {
+            warn!("Operation was canceled.");
+            return Ok(());
+        }
+        for (name, parameter) in parameters {
+            remote_config.put_parameter(name, parameter, self.group.clone());
+        }
+        self.network_service
+            .update_remote_config(project, response.data, response.etag)
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send, E: Editor + Send> Command for ImportDefaultsCommand<NS, E> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        let defaults = self.load_defaults()?;
+        self.run(project, &defaults).await
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        let defaults = self.load_defaults()?;
+        for project in projects {
+            if let Err(error) = self.run(project, &defaults).await {
+                error!("{}", error.red());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RemoteConfig {
+    /// Returns local defaults which are missing from the template or differ from its default values.
+    fn compare_defaults<'a>(
+        &'a self,
+        defaults: &'a BTreeMap<String, DefaultValue>,
+    ) -> Vec<(&'a str, Mismatch)> {
+        defaults
+            .iter()
+            .filter_map(|(name, value)| {
+                let parameter = match self.find_parameter(name) {
+                    None => return Some((name.as_str(), Mismatch::Missing)),
+                    Some((_, parameter)) => parameter,
+                };
+                if let Some(ParameterValue::UseInAppDefault(_)) = parameter.default_value {
+                    return None;
+                }
+                match DefaultValue::from_parameter(parameter) {
+                    Some(remote_value)
+                        if remote_value.value_type() == value.value_type()
+                            && remote_value.is_equivalent(value) =>
+                    {
+                        None
+                    }
+                    remote_value => Some((name.as_str(), Mismatch::Different(remote_value))),
+                }
+            })
+            .collect()
+    }
+}
+
+fn new_parameter(value: &DefaultValue) -> Parameter {
+    Parameter {
+        default_value: Some(ParameterValue::Value(value.as_str().to_string())),
+        conditional_values: HashMap::new(),
+        description: None,
+        value_type: value.value_type(),
+    }
+}
+
+fn make_table<'a>(
+    project_name: &str,
+    defaults: &BTreeMap<String, DefaultValue>,
+    mismatches: &[(&str, Mismatch)],
+) -> Table<'a> {
+    let mut table = Table::new();
+    table.style = TableStyle::simple();
+    table.max_column_width = 40;
+    let title = format!(
+        "{} default value(s) do not match {} project",
+        mismatches.len(),
+        project_name
+    );
+    table.add_row(Row::new(vec![TableCell::new_with_alignment(
+        title,
+        4,
+        Alignment::Center,
+    )]));
+    table.add_row(Row::new(vec![
+        TableCell::new("Name"),
+        TableCell::new("Status"),
+        TableCell::new("Local value"),
+        TableCell::new("Remote value"),
+    ]));
+    for (name, mismatch) in mismatches {
+        let local_value = &defaults[*name];
+        let (status, remote_value) = match mismatch {
+            Mismatch::Missing => ("missing".green().to_string(), None),
+            Mismatch::Different(remote_value) => {
+                ("different".yellow().to_string(), remote_value.as_ref())
+            }
+        };
+        // Types are shown only when they differ, otherwise the values look equal
+        let is_type_different = remote_value
+            .map(|value| value.value_type() != local_value.value_type())
+            .unwrap_or(false);
+        let cell_content = |value: &DefaultValue| {
+            if is_type_different {
+                format!("{} ({})", value.as_str(), value.value_type().cell_content())
+            } else {
+                value.as_str().to_string()
+            }
+        };
+        table.add_row(Row::new(vec![
+            TableCell::new(name),
+            TableCell::new(status),
+            TableCell::new(cell_content(local_value)),
+            TableCell::new(remote_value.map(cell_content).unwrap_or_default()),
+        ]));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::editor::MockEditor;
+    use crate::network::{MockNetworkService, ResponseWithEtag};
+    use crate::remote_config::ParameterValueType;
+
+    #[test]
+    fn missing_and_different_defaults_are_found() {
+        let mut config = RemoteConfig::default();
+        let mut parameter = Parameter::stub();
+        parameter.value_type = ParameterValueType::Number;
+        parameter.default_value = Some(ParameterValue::Value("1".to_string()));
+        config.parameters.insert("ratio".to_string(), parameter);
+        config
+            .parameters
+            .insert("upload_logs".to_string(), Parameter::stub());
+        let mut parameter = Parameter::stub();
+        parameter.default_value = Some(ParameterValue::UseInAppDefault(true));
+        config.parameters.insert("title".to_string(), parameter);
+        let mut parameter = Parameter::stub();
+        parameter.value_type = ParameterValueType::String;
+        parameter.default_value = Some(ParameterValue::Value("1".to_string()));
+        config.parameters.insert("version".to_string(), parameter);
+        let defaults = BTreeMap::from([
+            ("ratio".to_string(), DefaultValue::Number("1.0".to_string())),
+            ("upload_logs".to_string(), DefaultValue::Boolean(true)),
+            (
+                "title".to_string(),
+                DefaultValue::String("Hello".to_string()),
+            ),
+            ("camera".to_string(), DefaultValue::Boolean(false)),
+            ("version".to_string(), DefaultValue::Number("1".to_string())),
+        ]);
+
+        let mismatches = config.compare_defaults(&defaults);
+
+        assert_eq!(
+            mismatches,
+            vec![
+                ("camera", Mismatch::Missing),
+                (
+                    "upload_logs",
+                    Mismatch::Different(Some(DefaultValue::Boolean(false)))
+                ),
+                (
+                    "version",
+                    Mismatch::Different(Some(DefaultValue::String("1".to_string())))
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_names_are_not_added() {
+        let project = Project::new("Test".to_string(), "123".to_string(), vec![]);
+        let mut network_mock = MockNetworkService::new();
+        network_mock
+            .expect_get_remote_config()
+            .times(1)
+            .returning(|_| {
+                std::result::Result::Ok(ResponseWithEtag {
+                    etag: "e_tag".to_string(),
+                    data: RemoteConfig::default(),
+                })
+            });
+        network_mock
+            .expect_update_remote_config()
+            .times(1)
+            .withf(|_, config, _| {
+                let mut names = config.parameters.keys().collect::<Vec<_>>();
+                names.sort();
+                names == ["timeout"]
+            })
+            .returning(|_, _, _| std::result::Result::Ok(()));
+        let mut command = ImportDefaultsCommand::new(
+            PathBuf::new(),
+            None,
+            true,
+            Schemas::default(),
+            network_mock,
+            InputReader::new(MockEditor::new()),
+        );
+        let defaults = BTreeMap::from([
+            ("feature.enabled".to_string(), DefaultValue::Boolean(true)),
+            ("ios-flag".to_string(), DefaultValue::Boolean(false)),
+            (
+                "timeout".to_string(),
+                DefaultValue::Number("30".to_string()),
+            ),
+        ]);
+
+        command.run(&project, &defaults).await.unwrap();
+    }
+}
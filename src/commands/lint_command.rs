@@ -0,0 +1,135 @@
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::error::{Error, Result};
+use crate::network::NetworkService;
+use crate::remote_config::RemoteConfig;
+use crate::schema::{SchemaViolation, Schemas};
+use async_trait::async_trait;
+use color_eyre::owo_colors::OwoColorize;
+use term_table::row::Row;
+use term_table::table_cell::{Alignment, TableCell};
+use term_table::{Table, TableStyle};
+use tracing::{error, info};
+
+pub struct LintCommand<NS: NetworkService> {
+    schemas: Schemas,
+    network_service: NS,
+}
+
+impl<NS: NetworkService> LintCommand<NS> {
+    pub fn new(schemas: Schemas, network_service: NS) -> Self {
+        Self {
+            schemas,
+            network_service,
+        }
+    }
+
+    /// Returns number of found violations.
+    async fn run(&mut self, project: &Project) -> Result<usize> {
+        info!("Running for {} project", &project.name);
+        let response = self.network_service.get_remote_config(project).await?;
+        let violations = response.data.schema_violations(&self.schemas);
+        if violations.is_empty() {
+            info!(
+                "All parameters of {} project match their schemas",
+                &project.name
+            );
+            return Ok(0);
+        }
+
+        let mut table = Table::new();
+        table.style = TableStyle::simple();
+        table.max_column_width = 40;
+        let title = format!(
+            "{} schema violation(s) in {} project",
+            violations.len(),
+            &project.name
+        );
+        table.add_row(Row::new(vec![TableCell::new_with_alignment(
+            title,
+            4,
+            Alignment::Center,
+        )]));
+        table.add_row(Row::new(vec![
+            TableCell::new("Parameter"),
+            TableCell::new("Value"),
+            TableCell::new("Path"),
+            TableCell::new("Error"),
+        ]));
+        for (name, violation) in violations.iter() {
+            table.add_row(Row::new(vec![
+                TableCell::new(name),
+                TableCell::new(&violation.value),
+                TableCell::new(&violation.path),
+                TableCell::new(violation.message.red()),
+            ]));
+        }
+        println!("{}", table.render());
+        Ok(violations.len())
+    }
+
+    fn check_schemas(&self) -> Result<()> {
+        if self.schemas.is_empty() {
+            return Err(Error::new(
+                "No schemas found. Add them to the config file or the schema directory.",
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send> Command for LintCommand<NS> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        self.check_schemas()?;
+        match self.run(project).await? {
+            0 => Ok(()),
+            count => Err(Error {
+                message: format!("Found {} schema violation(s).", count),
+            }),
+        }
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        self.check_schemas()?;
+        let mut count = 0;
+        for project in projects {
+            match self.run(project).await {
+                Ok(project_count) => count += project_count,
+                Err(error) => error!("{}", error.red()),
+            }
+        }
+        match count {
+            0 => Ok(()),
+            count => Err(Error {
+                message: format!("Found {} schema violation(s).", count),
+            }),
+        }
+    }
+}
+
+impl RemoteConfig {
+    /// Violations of root and grouped parameters ordered by parameter name.
+    fn schema_violations(&self, schemas: &Schemas) -> Vec<(&str, SchemaViolation)> {
+        let mut parameters = self
+            .parameters
+            .iter()
+            .chain(
+                self.parameter_groups
+                    .values()
+                    .flat_map(|group| group.parameters.iter()),
+            )
+            .filter(|(name, _)| schemas.contains(name))
+            .collect::<Vec<_>>();
+        parameters.sort_by_key(|(name, _)| *name);
+        parameters
+            .into_iter()
+            .flat_map(|(name, parameter)| {
+                schemas
+                    .validate(name, parameter)
+                    .into_iter()
+                    .map(move |violation| (name.as_str(), violation))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,125 @@
+use super::output::{self, Records};
+use crate::cli::{Log, OutputFormat};
+use crate::error::Result;
+use crate::journal::{Journal, JournalEntry};
+use term_table::row::Row;
+use term_table::table_cell::TableCell;
+use term_table::{Table, TableStyle};
+use tracing::info;
+
+pub struct LogCommand {
+    arguments: Log,
+    journal: Journal,
+}
+
+impl LogCommand {
+    pub fn new(arguments: Log, app_name: String) -> Self {
+        Self {
+            arguments,
+            journal: Journal::new(app_name),
+        }
+    }
+
+    pub fn run(self) -> Result<()> {
+        let entries = self
+            .journal
+            .load()?
+            .into_iter()
+            .filter_map(|entry| self.filter(entry))
+            .collect::<Vec<_>>();
+        match self.arguments.output {
+            OutputFormat::Table => {
+                if entries.is_empty() {
+                    info!("No changes were found in the journal.");
+                } else {
+                    println!("{}", render(&entries).render());
+                }
+                Ok(())
+            }
+            format => output::print_structured(&entries, || records(&entries), format),
+        }
+    }
+
+    /// Returns entry matching all filters, only changes of matching parameters are kept.
+    fn filter(&self, mut entry: JournalEntry) -> Option<JournalEntry> {
+        let arguments = &self.arguments;
+        if let Some(project) = arguments.project.as_ref() {
+            if project.to_lowercase() != entry.project.to_lowercase() {
+                return None;
+            }
+        }
+        if arguments.since.map(|since| entry.timestamp < since) == Some(true)
+            || arguments.until.map(|until| entry.timestamp > until) == Some(true)
+        {
+            return None;
+        }
+        if let Some(pattern) = arguments.name.as_ref() {
+            entry
+                .changes
+                .retain(|change| change.is_parameter() && pattern.is_match(change.name()));
+            if entry.changes.is_empty() {
+                return None;
+            }
+        }
+        Some(entry)
+    }
+}
+
+fn render(entries: &[JournalEntry]) -> Table<'_> {
+    let mut table = Table::new();
+    table.style = TableStyle::simple();
+    table.max_column_width = 40;
+    let header: Vec<_> = ["Time (UTC)", "User", "Project", "Command", "Changes"]
+        .into_iter()
+        .map(TableCell::new)
+        .collect();
+    table.add_row(Row::new(header));
+    for entry in entries {
+        let changes = entry
+            .changes
+            .iter()
+            .map(|change| change.summary())
+            .collect::<Vec<_>>()
+            .join("\n");
+        table.add_row(Row::new(vec![
+            TableCell::new(entry.timestamp.format("%Y-%m-%d %H:%M:%S")),
+            TableCell::new(&entry.user),
+            TableCell::new(&entry.project),
+            TableCell::new(&entry.command),
+            TableCell::new(changes),
+        ]));
+    }
+    table
+}
+
+fn records(entries: &[JournalEntry]) -> Records {
+    let mut records = Records::new(&[
+        "timestamp",
+        "user",
+        "project",
+        "command",
+        "previous_etag",
+        "etag",
+        "change",
+    ]);
+    for entry in entries {
+        let row = |change: String| {
+            vec![
+                entry.timestamp.to_rfc3339(),
+                entry.user.clone(),
+                entry.project.clone(),
+                entry.command.clone(),
+                entry.previous_etag.clone(),
+                entry.etag.clone().unwrap_or_default(),
+                change,
+            ]
+        };
+        if entry.changes.is_empty() {
+            records.push(row(String::new()));
+        }
+        for change in entry.changes.iter() {
+            records.push(row(change.summary()));
+        }
+    }
+    records
+}
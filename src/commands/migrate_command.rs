@@ -1,19 +1,22 @@
+use super::add_command::expression_builder::replace_app_id;
+use crate::cli::{MigrateMode, MigrateOptions, ShowFilter};
 use crate::config::Project;
 use crate::editor::Editor;
 use crate::error::{Error, Result};
-use crate::io::InputReader;
+use crate::io::{self, InputReader};
 use crate::network::NetworkService;
-use crate::remote_config::{Parameter, ParameterGroup, RemoteConfig};
+use crate::remote_config::{Condition, Parameter, ParameterGroup, RemoteConfig};
 #[cfg(test)]
-use crate::remote_config::{ParameterValue, ParameterValueType};
+use crate::remote_config::{ParameterValue, ParameterValueType, TagColor};
 use colored::Colorize;
 use std::collections::{HashMap, HashSet};
 use term_table::row::Row;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 pub struct MigrateCommand<'a, NS: NetworkService, E: Editor> {
     source_project: &'a Project,
     destinations: Vec<&'a Project>,
+    options: MigrateOptions,
     network_service: NS,
     input_reader: InputReader<E>,
 }
@@ -29,16 +32,38 @@ struct NewParameterGroup<'a> {
     description: Option<&'a str>,
 }
 
+struct UpdatedParameter {
+    name: String,
+    group: Option<String>,
+    previous: Parameter,
+    parameter: Parameter,
+}
+
+struct DeletedParameter {
+    name: String,
+    group: Option<String>,
+    parameter: Parameter,
+}
+
+/// Changes which will be applied to the destination project.
+struct MigrationPlan<'a> {
+    new_parameters: Vec<NewParameter<'a>>,
+    updated_parameters: Vec<UpdatedParameter>,
+    deleted_parameters: Vec<DeletedParameter>,
+}
+
 impl<'a, NS: NetworkService, E: Editor> MigrateCommand<'a, NS, E> {
     pub fn new(
         source_project: &'a Project,
         destinations: Vec<&'a Project>,
+        options: MigrateOptions,
         network_service: NS,
         input_reader: InputReader<E>,
     ) -> Self {
         Self {
             source_project,
             destinations,
+            options,
             network_service,
             input_reader,
         }
@@ -47,6 +72,7 @@ impl<'a, NS: NetworkService, E: Editor> MigrateCommand<'a, NS, E> {
     pub fn new_for_all_projects(
         source_project_name: String,
         projects: &'a [Project],
+        options: MigrateOptions,
         network_service: NS,
         input_reader: InputReader<E>,
     ) -> Result<MigrateCommand<'a, NS, E>> {
@@ -67,6 +93,7 @@ impl<'a, NS: NetworkService, E: Editor> MigrateCommand<'a, NS, E> {
         Ok(Self {
             source_project,
             destinations: projects,
+            options,
             network_service,
             input_reader,
         })
@@ -76,6 +103,7 @@ impl<'a, NS: NetworkService, E: Editor> MigrateCommand<'a, NS, E> {
         source_project_name: String,
         destinations: Vec<String>,
         projects: &'a [Project],
+        options: MigrateOptions,
         network_service: NS,
         input_reader: InputReader<E>,
     ) -> Result<MigrateCommand<'a, NS, E>> {
@@ -97,6 +125,7 @@ impl<'a, NS: NetworkService, E: Editor> MigrateCommand<'a, NS, E> {
         Ok(Self {
             source_project,
             destinations,
+            options,
             network_service,
             input_reader,
         })
@@ -117,13 +146,37 @@ impl<'a, NS: NetworkService, E: Editor> MigrateCommand<'a, NS, E> {
             info!("Running for {} project", &project.name);
             let mut response = self.network_service.get_remote_config(project).await?;
             let destination = &mut response.data;
-            let existing_names = destination.existing_parameter_names();
-            let new_parameters = source.find_new_parameters(&existing_names);
+            let mut plan = MigrationPlan::new(&source, destination, &self.options);
+            plan.retain_matching(&self.options);
 
-            if new_parameters.is_empty() {
-                println!("No new parameters was found.");
+            if plan.is_empty() {
+                println!("No changes were found.");
+                continue;
+            }
+            if self.options.pick {
+                plan.pick();
+            } else {
+                plan.review(&mut self.input_reader);
+            }
+            if plan.is_empty() {
+                warn!("Operation was canceled.");
                 continue;
             }
+            if self.options.with_conditions {
+                let mut parameters = plan.parameters_mut();
+                destination.migrate_conditions(&source, &mut parameters, &project.app_ids);
+            }
+            for updated in plan.updated_parameters {
+                if let Some(map) = destination.get_map_for_existing_parameter(&updated.name) {
+                    map.insert(updated.name, updated.parameter);
+                }
+            }
+            for deleted in plan.deleted_parameters {
+                if let Some(map) = destination.get_map_for_existing_parameter(&deleted.name) {
+                    map.remove(&deleted.name);
+                }
+            }
+            let new_parameters = plan.new_parameters;
             destination.render(&project.name, &new_parameters);
 
             for parameter in new_parameters {
@@ -158,6 +211,7 @@ impl<'a, NS: NetworkService, E: Editor> MigrateCommand<'a, NS, E> {
             }
 
             if !self.input_reader.ask_confirmation("Confirm: [Y,n]") {
+                warn!("Operation was canceled.");
                 continue;
             }
             self.network_service
@@ -180,7 +234,18 @@ impl RemoteConfig {
             })
     }
 
-    fn find_new_parameters<'b>(&self, existing_names: &'b HashSet<&str>) -> Vec<NewParameter> {
+    fn find_new_parameters<'b>(
+        &self,
+        existing_names: &'b HashSet<&str>,
+        with_conditions: bool,
+    ) -> Vec<NewParameter> {
+        let clone_parameter = |parameter: &Parameter| {
+            if with_conditions {
+                parameter.clone()
+            } else {
+                parameter.clone_without_coniditional_values()
+            }
+        };
         let new_root_parameters = self.parameters.iter().filter_map(|(name, parameter)| {
             if existing_names.contains(name.as_str()) {
                 None
@@ -188,7 +253,7 @@ impl RemoteConfig {
                 Some(NewParameter {
                     group: None,
                     name: name.clone(),
-                    parameter: parameter.clone_without_coniditional_values(),
+                    parameter: clone_parameter(parameter),
                 })
             }
         });
@@ -208,7 +273,7 @@ impl RemoteConfig {
                         Some(NewParameter {
                             group: Some(parameter_group),
                             name: name.clone(),
-                            parameter: parameter.clone_without_coniditional_values(),
+                            parameter: clone_parameter(parameter),
                         })
                     }
                 })
@@ -219,10 +284,150 @@ impl RemoteConfig {
             .collect::<Vec<_>>()
     }
 
+    fn find_updated_parameters(
+        &self,
+        destination: &RemoteConfig,
+        with_conditions: bool,
+    ) -> Vec<UpdatedParameter> {
+        let root_parameters = self.parameters.iter();
+        let group_parameters = self
+            .parameter_groups
+            .values()
+            .flat_map(|group| group.parameters.iter());
+        root_parameters
+            .chain(group_parameters)
+            .filter_map(|(name, parameter)| {
+                let (group, previous) = destination.find_parameter(name)?;
+                let mut updated = previous.clone();
+                updated.default_value = parameter.default_value.clone();
+                updated.value_type = parameter.value_type;
+                updated.description = parameter.description.clone();
+                if with_conditions {
+                    updated.conditional_values = parameter.conditional_values.clone();
+                }
+                if &updated == previous {
+                    return None;
+                }
+                Some(UpdatedParameter {
+                    name: name.clone(),
+                    group: group.map(|name| name.to_string()),
+                    previous: previous.clone(),
+                    parameter: updated,
+                })
+            })
+            .collect()
+    }
+
+    fn find_deleted_parameters(&self, destination: &RemoteConfig) -> Vec<DeletedParameter> {
+        let source_names = self.existing_parameter_names();
+        let root_parameters = destination
+            .parameters
+            .iter()
+            .map(|(name, parameter)| (name, None, parameter));
+        let group_parameters =
+            destination
+                .parameter_groups
+                .iter()
+                .flat_map(|(group_name, group)| {
+                    group
+                        .parameters
+                        .iter()
+                        .map(move |(name, parameter)| (name, Some(group_name), parameter))
+                });
+        root_parameters
+            .chain(group_parameters)
+            .filter(|(name, _, _)| !source_names.contains(name.as_str()))
+            .map(|(name, group, parameter)| DeletedParameter {
+                name: name.clone(),
+                group: group.cloned(),
+                parameter: parameter.clone(),
+            })
+            .collect()
+    }
+
+    /// Adds conditions referenced by conditional values of new parameters.
+    /// Conditional values are dropped if their condition can not be migrated.
+    fn migrate_conditions(
+        &mut self,
+        source: &RemoteConfig,
+        parameters: &mut [&mut Parameter],
+        app_ids: &[String],
+    ) {
+        let condition_names = parameters
+            .iter()
+            .flat_map(|parameter| parameter.conditional_values.keys())
+            .cloned()
+            .collect::<HashSet<_>>();
+        let mut skipped_conditions = HashSet::new();
+        // Conditions are migrated in the source order to keep their relative priority.
+        let source_conditions = source
+            .conditions
+            .iter()
+            .filter(|condition| condition_names.contains(&condition.name));
+        for source_condition in source_conditions {
+            let mut condition = source_condition.clone();
+            if let Err(error) = replace_app_id(&mut condition.expression, app_ids) {
+                let message = format!(
+                    "Condition {} will not be migrated: {}",
+                    &condition.name, error.message
+                );
+                warn!("{}", message.yellow());
+                skipped_conditions.insert(condition.name);
+                continue;
+            }
+            let existing_condition = self
+                .conditions
+                .iter_mut()
+                .find(|existing| existing.name == condition.name);
+            match existing_condition {
+                None => {
+                    info!("Condition {} will be created", &condition.name);
+                    self.conditions.push(condition);
+                }
+                Some(existing) if existing.expression == condition.expression => {}
+                Some(existing) => {
+                    if !Self::resolve_condition_conflict(existing, condition) {
+                        skipped_conditions.insert(existing.name.clone());
+                    }
+                }
+            }
+        }
+        for parameter in parameters.iter_mut() {
+            parameter.conditional_values.retain(|name, _| {
+                source
+                    .conditions
+                    .iter()
+                    .any(|condition| &condition.name == name)
+                    && !skipped_conditions.contains(name)
+            });
+        }
+    }
+
+    /// Returns false if conditional values for the condition must be skipped.
+    fn resolve_condition_conflict(existing: &mut Condition, condition: Condition) -> bool {
+        let label = format!(
+            "Condition {} already exists with different expression.\n  Destination: {}\n  Source: {}\n  Select what to do:",
+            &existing.name, &existing.expression, &condition.expression
+        );
+        let options = [
+            "Keep destination condition",
+            "Replace destination condition with source one",
+            "Skip conditional values for this condition",
+        ];
+        match io::request_select_item_in_list(&label, options.iter().copied(), None) {
+            Some(0) => true,
+            Some(1) => {
+                existing.expression = condition.expression;
+                true
+            }
+            _ => false,
+        }
+    }
+
     fn render(&self, project_name: &str, new_parameters: &Vec<NewParameter>) {
         let new_parameter_rows = new_parameters.iter().flat_map(|param| param.make_rows());
 
-        let mut table = self.build_table(project_name);
+        let mut table = self.build_table(project_name, &ShowFilter::default());
         let rows = &mut table.rows;
         rows.reserve(new_parameters.len());
         let mut condition_rows = rows.split_off(rows.len() - self.conditions.len());
@@ -245,6 +450,130 @@ impl Parameter {
     }
 }
 
+impl<'a> MigrationPlan<'a> {
+    fn new(source: &'a RemoteConfig, destination: &RemoteConfig, options: &MigrateOptions) -> Self {
+        let existing_names = destination.existing_parameter_names();
+        let new_parameters = source.find_new_parameters(&existing_names, options.with_conditions);
+        let updated_parameters = match options.mode {
+            MigrateMode::Add => Vec::new(),
+            MigrateMode::Sync | MigrateMode::Mirror => {
+                source.find_updated_parameters(destination, options.with_conditions)
+            }
+        };
+        let deleted_parameters = match options.mode {
+            MigrateMode::Add | MigrateMode::Sync => Vec::new(),
+            MigrateMode::Mirror => source.find_deleted_parameters(destination),
+        };
+        Self {
+            new_parameters,
+            updated_parameters,
+            deleted_parameters,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.new_parameters.is_empty()
+            && self.updated_parameters.is_empty()
+            && self.deleted_parameters.is_empty()
+    }
+
+    /// Asks user to accept or reject every change. Rejected changes are removed from the plan.
+    fn review<E: Editor>(&mut self, input_reader: &mut InputReader<E>) {
+        let message = "Apply this change? [Y,n]";
+        self.new_parameters.retain(|new_parameter| {
+            let group_name = new_parameter.group.as_ref().map(|group| group.name);
+            new_parameter.parameter.preview(
+                &new_parameter.name,
+                "Parameter will be added",
+                group_name,
+            );
+            input_reader.ask_confirmation(message)
+        });
+        self.updated_parameters.retain(|updated| {
+            let group_name = updated.group.as_deref();
+            updated
+                .previous
+                .preview(&updated.name, "Current parameter values", group_name);
+            updated
+                .parameter
+                .preview(&updated.name, "Parameter will be updated", group_name);
+            input_reader.ask_confirmation(message)
+        });
+        self.deleted_parameters.retain(|deleted| {
+            deleted.parameter.preview(
+                &deleted.name,
+                "Parameter will be deleted",
+                deleted.group.as_deref(),
+            );
+            input_reader.ask_confirmation(message)
+        });
+    }
+
+    /// Removes changes for parameters which do not pass include, exclude and group filters.
+    fn retain_matching(&mut self, options: &MigrateOptions) {
+        let is_matching = |name: &str, group_name: Option<&str>| {
+            let is_included = options.include.is_empty()
+                || options.include.iter().any(|pattern| pattern.is_match(name));
+            let is_excluded = options.exclude.iter().any(|pattern| pattern.is_match(name));
+            let is_group_matching = options.group.is_empty()
+                || group_name
+                    .map(|group_name| options.group.iter().any(|group| group == group_name))
+                    .unwrap_or(false);
+            is_included && !is_excluded && is_group_matching
+        };
+        self.new_parameters.retain(|new_parameter| {
+            let group_name = new_parameter.group.as_ref().map(|group| group.name);
+            is_matching(&new_parameter.name, group_name)
+        });
+        self.updated_parameters
+            .retain(|updated| is_matching(&updated.name, updated.group.as_deref()));
+        self.deleted_parameters
+            .retain(|deleted| is_matching(&deleted.name, deleted.group.as_deref()));
+    }
+
+    /// Asks user to check changes which must be applied. Unchecked changes are removed from the plan.
+    fn pick(&mut self) {
+        let labels = self
+            .new_parameters
+            .iter()
+            .map(|new_parameter| format!("Add {}", new_parameter.name))
+            .chain(
+                self.updated_parameters
+                    .iter()
+                    .map(|updated| format!("Update {}", updated.name)),
+            )
+            .chain(
+                self.deleted_parameters
+                    .iter()
+                    .map(|deleted| format!("Delete {}", deleted.name)),
+            )
+            .collect::<Vec<_>>();
+        let labels = labels.iter().map(String::as_str).collect::<Vec<_>>();
+        let label = "Select changes to apply:";
+        let selected = io::request_select_items_in_list(label, &labels, true)
+            .unwrap_or_default()
+            .into_iter()
+            .collect::<HashSet<_>>();
+        let mut indices = 0..;
+        let mut is_selected = || selected.contains(&indices.next().unwrap());
+        self.new_parameters.retain(|_| is_selected());
+        self.updated_parameters.retain(|_| is_selected());
+        self.deleted_parameters.retain(|_| is_selected());
+    }
+
+    fn parameters_mut(&mut self) -> Vec<&mut Parameter> {
+        let new_parameters = self
+            .new_parameters
+            .iter_mut()
+            .map(|new_parameter| &mut new_parameter.parameter);
+        let updated_parameters = self
+            .updated_parameters
+            .iter_mut()
+            .map(|updated| &mut updated.parameter);
+        new_parameters.chain(updated_parameters).collect()
+    }
+}
+
 impl<'a> NewParameter<'a> {
     fn make_rows(&self) -> Vec<Row> {
         let group_name = self.group.as_ref().map(|v| v.name);
@@ -297,12 +626,13 @@ mod tests {
         let mut editor_mock = MockEditor::new();
         editor_mock
             .expect_read_line()
-            .times(1)
+            .times(2)
             .returning(|| Result::Ok("y".to_string()));
 
         let command = MigrateCommand::new(
             &source_project,
             vec![&destination_project],
+            MigrateOptions::default(),
             network_mock,
             InputReader::new(editor_mock),
         );
@@ -310,6 +640,77 @@ mod tests {
         let result = command.run().await;
         assert_eq!(result.is_ok(), true);
     }
+
+    #[test]
+    fn sync_plan_contains_drifted_parameters() {
+        let mut source = RemoteConfig::new_with_root_stub_parameter("upload_logs");
+        source
+            .parameters
+            .get_mut("upload_logs")
+            .unwrap()
+            .default_value = Some(ParameterValue::Value("true".to_string()));
+        let mut destination = RemoteConfig::new_with_root_stub_parameter("upload_logs");
+        destination
+            .parameters
+            .insert("legacy_flag".to_string(), Parameter::stub());
+        let options = MigrateOptions {
+            mode: MigrateMode::Mirror,
+            ..Default::default()
+        };
+
+        let plan = MigrationPlan::new(&source, &destination, &options);
+
+        assert!(plan.new_parameters.is_empty());
+        assert_eq!(plan.updated_parameters.len(), 1);
+        assert_eq!(
+            plan.updated_parameters[0].parameter.default_value,
+            Some(ParameterValue::Value("true".to_string()))
+        );
+        assert_eq!(plan.deleted_parameters.len(), 1);
+        assert_eq!(plan.deleted_parameters[0].name, "legacy_flag");
+    }
+
+    #[test]
+    fn conditions_migrated_with_replaced_app_id() {
+        let condition = Condition {
+            name: "iOS".to_string(),
+            expression: "app.id == '1:111:ios:aaa'".to_string(),
+            tag_color: TagColor::Blue,
+        };
+        let mut parameter = Parameter::stub();
+        parameter.conditional_values.insert(
+            condition.name.clone(),
+            ParameterValue::Value("true".to_string()),
+        );
+        parameter.conditional_values.insert(
+            "Unknown".to_string(),
+            ParameterValue::Value("true".to_string()),
+        );
+        let mut source = RemoteConfig::default();
+        source.conditions.push(condition);
+        source
+            .parameters
+            .insert("upload_logs".to_string(), parameter);
+        let mut destination = RemoteConfig::default();
+
+        let existing_names = destination.existing_parameter_names();
+        let mut new_parameters = source.find_new_parameters(&existing_names, true);
+        let app_ids = ["1:222:ios:bbb".to_string()];
+        let mut parameters = new_parameters
+            .iter_mut()
+            .map(|new_parameter| &mut new_parameter.parameter)
+            .collect::<Vec<_>>();
+        destination.migrate_conditions(&source, &mut parameters, &app_ids);
+
+        assert_eq!(destination.conditions.len(), 1);
+        assert_eq!(
+            destination.conditions[0].expression,
+            "app.id == '1:222:ios:bbb'"
+        );
+        let conditional_values = &new_parameters[0].parameter.conditional_values;
+        assert_eq!(conditional_values.len(), 1);
+        assert!(conditional_values.contains_key("iOS"));
+    }
 }
 
 #[cfg(test)]
@@ -336,7 +737,7 @@ impl RemoteConfig {
 
 #[cfg(test)]
 impl Parameter {
-    fn stub() -> Self {
+    pub fn stub() -> Self {
         Parameter {
             default_value: Some(ParameterValue::Value("false".to_string())),
             conditional_values: HashMap::new(),
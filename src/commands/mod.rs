@@ -1,21 +1,58 @@
 mod add_command;
+mod backup_command;
+mod batch_command;
+mod codegen_command;
 mod command;
 mod config_command;
+mod defaults_command;
 mod delete_command;
+mod edit_command;
+mod flag_command;
+mod gc_command;
+mod group_command;
+mod import_defaults_command;
+mod lint_command;
+mod log_command;
 mod migrate_command;
 mod move_out_command;
 mod move_to_command;
+mod output;
 mod remote_config_table;
+mod restore_command;
+mod rollout_command;
+mod schedule_command;
 mod show_command;
+mod stats_command;
+mod undo_command;
 mod update_command;
+mod watch_command;
+mod where_used_command;
 
 pub use add_command::AddCommand;
+pub use backup_command::BackupCommand;
+pub use batch_command::BatchCommand;
+pub use codegen_command::CodegenCommand;
 pub use command::CommandRunner;
 pub use config_command::ConfigCommand;
 pub use config_command::ConfigFile;
+pub use defaults_command::DefaultsCommand;
 pub use delete_command::DeleteCommand;
+pub use edit_command::EditCommand;
+pub use flag_command::FlagCommand;
+pub use gc_command::GcCommand;
+pub use group_command::GroupCommand;
+pub use import_defaults_command::ImportDefaultsCommand;
+pub use lint_command::LintCommand;
+pub use log_command::LogCommand;
 pub use migrate_command::MigrateCommand;
 pub use move_out_command::MoveOutCommand;
 pub use move_to_command::MoveToCommand;
+pub use restore_command::RestoreCommand;
+pub use rollout_command::RolloutCommand;
+pub use schedule_command::ScheduleCommand;
 pub use show_command::ShowCommand;
+pub use stats_command::StatsCommand;
+pub use undo_command::UndoCommand;
 pub use update_command::UpdateCommand;
+pub use watch_command::WatchCommand;
+pub use where_used_command::WhereUsedCommand;
@@ -0,0 +1,117 @@
+use crate::cli::OutputFormat;
+use crate::error::{Error, Result};
+use serde::Serialize;
+use std::fmt::Write;
+use tracing::debug;
+
+/// Flat representation of command output used for CSV and Markdown formats.
+pub struct Records {
+    header: &'static [&'static str],
+    rows: Vec<Vec<String>>,
+}
+
+impl Records {
+    pub fn new(header: &'static [&'static str]) -> Self {
+        Self {
+            header,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        debug_assert_eq!(row.len(), self.header.len());
+        self.rows.push(row);
+    }
+
+    fn to_csv(&self) -> String {
+        let mut result = String::new();
+        let header = self.header.iter().map(|column| column.to_string());
+        Self::write_csv_row(&mut result, header);
+        for row in self.rows.iter() {
+            Self::write_csv_row(&mut result, row.iter().cloned());
+        }
+        result
+    }
+
+    fn write_csv_row(result: &mut String, row: impl Iterator<Item = String>) {
+        let row = row
+            .map(|field| {
+                if field.contains(&[',', '"', '\n', '\r'][..]) {
+                    format!("\"{}\"", field.replace('"', "\"\""))
+                } else {
+                    field
+                }
+            })
+            .collect::<Vec<_>>();
+        writeln!(result, "{}", row.join(",")).unwrap();
+    }
+
+    fn to_markdown(&self) -> String {
+        let mut result = String::new();
+        writeln!(result, "| {} |", self.header.join(" | ")).unwrap();
+        let separator = vec!["---"; self.header.len()];
+        writeln!(result, "| {} |", separator.join(" | ")).unwrap();
+        for row in self.rows.iter() {
+            let row = row
+                .iter()
+                .map(|field| {
+                    field
+                        .replace('|', "\\|")
+                        .replace("\r\n", "<br>")
+                        .replace('\n', "<br>")
+                })
+                .collect::<Vec<_>>();
+            writeln!(result, "| {} |", row.join(" | ")).unwrap();
+        }
+        result
+    }
+}
+
+/// Prints `value` in structured `format`.
+/// Records are only built for formats that need a flat representation.
+pub fn print_structured<T, F>(value: &T, make_records: F, format: OutputFormat) -> Result<()>
+where
+    T: Serialize,
+    F: FnOnce() -> Records,
+{
+    let output = match format {
+        OutputFormat::Table => {
+            return Err(Error::new("Table output must be rendered by the command."))
+        }
+        OutputFormat::Json => serde_json::to_string_pretty(value).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error::new("Failed to serialize output to JSON.")
+        })?,
+        OutputFormat::Yaml => serde_yaml::to_string(value).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error::new("Failed to serialize output to YAML.")
+        })?,
+        OutputFormat::Csv => make_records().to_csv(),
+        OutputFormat::Markdown => make_records().to_markdown(),
+    };
+    println!("{}", output.trim_end());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_escaping() {
+        let mut records = Records::new(&["name", "value"]);
+        records.push(vec![
+            "flag".to_string(),
+            "{\"a\":1,\"b\":\"|\"}".to_string(),
+        ]);
+
+        assert_eq!(
+            records.to_csv(),
+            "name,value\nflag,\"{\"\"a\"\":1,\"\"b\"\":\"\"|\"\"}\"\n"
+        );
+        assert_eq!(
+            records.to_markdown(),
+            "| name | value |\n| --- | --- |\n| flag | {\"a\":1,\"b\":\"\\|\"} |\n"
+        );
+    }
+}
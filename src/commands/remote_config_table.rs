@@ -1,37 +1,50 @@
+use crate::cli::ShowFilter;
 use crate::remote_config::{
-    Condition, Parameter, ParameterValue, ParameterValueType, RemoteConfig,
+    Condition, Parameter, ParameterGroup, ParameterValue, ParameterValueType, RemoteConfig,
 };
+use color_eyre::owo_colors::OwoColorize;
+use std::collections::{BTreeSet, HashMap};
 use term_table::row::Row;
 use term_table::table_cell::{Alignment, TableCell};
 use term_table::{Table, TableStyle};
 
 impl RemoteConfig {
-    pub fn build_table<'a, 'b>(&'a self, project_name: &'b str) -> Table<'a> {
+    pub fn build_table<'a, 'b>(&'a self, project_name: &'b str, filter: &ShowFilter) -> Table<'a> {
         let mut table = Table::new();
         table.max_column_width = 25;
         table.style = TableStyle::simple();
 
-        let title = format!("{} parameters", project_name);
-        table.add_row(Self::make_title_row(title));
-        self.parameters
-            .iter()
-            .flat_map(|(name, parameter)| parameter.make_row(name, None))
-            .for_each(|row| table.add_row(row));
+        if !filter.conditions_only {
+            let title = format!("{} parameters", project_name);
+            table.add_row(Self::make_title_row(title));
+            self.parameters
+                .iter()
+                .filter(|(name, parameter)| filter.is_parameter_included(name, None, parameter))
+                .flat_map(|(name, parameter)| parameter.make_row(name, None))
+                .for_each(|row| table.add_row(row));
 
-        self.parameter_groups
-            .iter()
-            .flat_map(|(group_name, group)| {
-                group
-                    .parameters
-                    .iter()
-                    .flat_map(|(name, parameter)| parameter.make_row(name, Some(group_name)))
-            })
-            .for_each(|row| table.add_row(row));
+            self.parameter_groups
+                .iter()
+                .flat_map(|(group_name, group)| {
+                    group
+                        .parameters
+                        .iter()
+                        .filter(|(name, parameter)| {
+                            filter.is_parameter_included(name, Some(group_name), parameter)
+                        })
+                        .flat_map(|(name, parameter)| parameter.make_row(name, Some(group_name)))
+                })
+                .for_each(|row| table.add_row(row));
+        }
 
-        if !self.conditions.is_empty() {
+        let mut conditions = self
+            .conditions
+            .iter()
+            .filter(|condition| filter.is_condition_included(condition))
+            .peekable();
+        if conditions.peek().is_some() {
             table.add_row(Self::make_title_row("Conditions".to_string()));
-            self.conditions
-                .iter()
+            conditions
                 .map(|condition| condition.make_row())
                 .for_each(|row| table.add_row(row))
         }
@@ -47,7 +60,96 @@ impl RemoteConfig {
     }
 }
 
+/// Project name with the parameter and its group name, if project contains it.
+pub type MatrixEntry<'a> = (&'a str, Option<(Option<&'a str>, &'a Parameter)>);
+
+/// Builds a table with a row per project for a single parameter.
+/// Cells which differ from the first project containing the parameter are highlighted.
+pub fn build_matrix_table(name: &str, entries: &[MatrixEntry]) -> Table<'static> {
+    let mut table = Table::new();
+    table.max_column_width = 25;
+    table.style = TableStyle::simple();
+
+    let condition_names = entries
+        .iter()
+        .filter_map(|(_, entry)| entry.as_ref())
+        .flat_map(|(_, parameter)| parameter.conditional_values.keys())
+        .map(String::as_str)
+        .collect::<BTreeSet<_>>();
+    let columns_count = condition_names.len() + 4;
+
+    table.add_row(Row::new(vec![TableCell::new_with_alignment(
+        name,
+        columns_count,
+        Alignment::Center,
+    )]));
+    let header: Vec<_> = ["Project", "Type", "Default value", "Group"]
+        .into_iter()
+        .chain(condition_names.iter().copied())
+        .map(TableCell::new)
+        .collect();
+    table.add_row(Row::new(header));
+
+    let reference = entries.iter().find_map(|(_, entry)| entry.as_ref());
+    for (project_name, entry) in entries {
+        let (group_name, parameter) = match entry {
+            None => {
+                let cells = vec![
+                    TableCell::new(project_name),
+                    TableCell::new_with_col_span("Missing".red().to_string(), columns_count - 1),
+                ];
+                table.add_row(Row::new(cells));
+                continue;
+            }
+            Some(entry) => entry,
+        };
+        let (reference_group, reference_parameter) = reference.unwrap();
+        let mut cells = vec![
+            TableCell::new(project_name),
+            matrix_cell(
+                parameter.value_type.cell_content(),
+                reference_parameter.value_type.cell_content(),
+            ),
+            matrix_cell(
+                parameter.default_value_cell_content(),
+                reference_parameter.default_value_cell_content(),
+            ),
+            matrix_cell(group_name.unwrap_or(""), reference_group.unwrap_or("")),
+        ];
+        condition_names
+            .iter()
+            .map(|condition| {
+                let content = |parameter: &Parameter| {
+                    parameter
+                        .conditional_values
+                        .get(*condition)
+                        .map(|value| value.cell_content().to_string())
+                        .unwrap_or_default()
+                };
+                matrix_cell(&content(parameter), &content(reference_parameter))
+            })
+            .for_each(|cell| cells.push(cell));
+        table.add_row(Row::new(cells));
+    }
+    table
+}
+
+fn matrix_cell(content: &str, reference_content: &str) -> TableCell<'static> {
+    if content == reference_content {
+        TableCell::new(content)
+    } else {
+        TableCell::new(content.yellow().to_string())
+    }
+}
+
 impl Parameter {
+    fn default_value_cell_content(&self) -> &str {
+        self.default_value
+            .as_ref()
+            .map(|value| value.cell_content())
+            .unwrap_or("")
+    }
+
     pub fn make_row<N: ToString>(&self, name: N, group_name: Option<&str>) -> Vec<Row> {
         let rows_count = self.conditional_values.len() + 1;
         let mut rows = Vec::with_capacity(rows_count);
@@ -55,12 +157,7 @@ impl Parameter {
             TableCell::new(name),
             TableCell::new(""),
             TableCell::new(self.value_type.cell_content()),
-            TableCell::new(
-                self.default_value
-                    .as_ref()
-                    .map(|value| value.cell_content())
-                    .unwrap_or(""),
-            ),
+            TableCell::new(self.default_value_cell_content()),
             TableCell::new(group_name.unwrap_or("")),
         ]);
         rows.push(default_row);
@@ -109,8 +206,108 @@ impl Condition {
     }
 }
 
+impl ShowFilter {
+    pub fn is_parameter_included(
+        &self,
+        name: &str,
+        group_name: Option<&str>,
+        parameter: &Parameter,
+    ) -> bool {
+        let is_name_matched = self
+            .name
+            .as_ref()
+            .map(|pattern| pattern.is_match(name))
+            .unwrap_or(true);
+        let is_group_matched = self
+            .group
+            .as_ref()
+            .map(|group| Some(group.as_str()) == group_name)
+            .unwrap_or(true);
+        let is_type_matched = self
+            .value_type
+            .map(|value_type| value_type == parameter.value_type)
+            .unwrap_or(true);
+        let is_condition_matched = self
+            .condition
+            .as_ref()
+            .map(|condition| parameter.conditional_values.contains_key(condition))
+            .unwrap_or(true);
+        let is_changed = !self.changed_from_default || parameter.is_changed_from_default();
+
+        is_name_matched && is_group_matched && is_type_matched && is_condition_matched && is_changed
+    }
+
+    pub fn is_condition_included(&self, condition: &Condition) -> bool {
+        self.condition
+            .as_ref()
+            .map(|name| name == &condition.name)
+            .unwrap_or(true)
+    }
+}
+
+impl RemoteConfig {
+    /// Returns copy of the config which contains only parameters and conditions included by filter
+    pub fn filtered(&self, filter: &ShowFilter) -> RemoteConfig {
+        let conditions = self
+            .conditions
+            .iter()
+            .filter(|condition| filter.is_condition_included(condition))
+            .cloned()
+            .collect();
+        if filter.conditions_only {
+            return RemoteConfig {
+                conditions,
+                parameters: HashMap::new(),
+                parameter_groups: HashMap::new(),
+            };
+        }
+        let parameters = self
+            .parameters
+            .iter()
+            .filter(|(name, parameter)| filter.is_parameter_included(name, None, parameter))
+            .map(|(name, parameter)| (name.clone(), parameter.clone()))
+            .collect();
+        let parameter_groups = self
+            .parameter_groups
+            .iter()
+            .filter_map(|(group_name, group)| {
+                let parameters = group
+                    .parameters
+                    .iter()
+                    .filter(|(name, parameter)| {
+                        filter.is_parameter_included(name, Some(group_name), parameter)
+                    })
+                    .map(|(name, parameter)| (name.clone(), parameter.clone()))
+                    .collect::<HashMap<_, _>>();
+                if parameters.is_empty() {
+                    None
+                } else {
+                    let group = ParameterGroup {
+                        description: group.description.clone(),
+                        parameters,
+                    };
+                    Some((group_name.clone(), group))
+                }
+            })
+            .collect();
+        RemoteConfig {
+            conditions,
+            parameters,
+            parameter_groups,
+        }
+    }
+}
+
+impl Parameter {
+    fn is_changed_from_default(&self) -> bool {
+        self.conditional_values
+            .values()
+            .any(|value| Some(value) != self.default_value.as_ref())
+    }
+}
+
 impl ParameterValueType {
-    fn cell_content(&self) -> &str {
+    pub fn cell_content(&self) -> &str {
         match self {
             Self::String => "String",
             Self::Boolean => "Bool",
@@ -122,7 +319,7 @@ impl ParameterValueType {
 }
 
 impl ParameterValue {
-    fn cell_content(&self) -> &str {
+    pub fn cell_content(&self) -> &str {
         match self {
             Self::Value(string) => string,
             Self::UseInAppDefault(_) => "Use in app default",
@@ -0,0 +1,213 @@
+use super::backup_command::{Manifest, MANIFEST_FILE_NAME};
+use super::edit_command::print_diff;
+use crate::cli::EditFormat;
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::editor::Editor;
+use crate::error::{Error, Result};
+use crate::io::InputReader;
+use crate::network::NetworkService;
+use crate::remote_config::RemoteConfig;
+use async_trait::async_trait;
+use color_eyre::owo_colors::OwoColorize;
+use std::fs;
+use std::path::PathBuf;
+use tracing::{debug, error, info, warn};
+
+pub struct RestoreCommand<NS: NetworkService, E: Editor> {
+    from: PathBuf,
+    network_service: NS,
+    input_reader: InputReader<E>,
+}
+
+impl<NS: NetworkService, E: Editor> RestoreCommand<NS, E> {
+    pub fn new(from: PathBuf, network_service: NS, input_reader: InputReader<E>) -> Self {
+        Self {
+            from,
+            network_service,
+            input_reader,
+        }
+    }
+
+    fn load_manifest(&self) -> Result<Manifest> {
+        let path = self.from.join(MANIFEST_FILE_NAME);
+        let content = fs::read_to_string(&path).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error {
+                message: format!("Backup manifest was not found at {:?}.", path),
+            }
+        })?;
+        serde_json::from_str(&content).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error::new("Failed to parse backup manifest.")
+        })
+    }
+
+    async fn run(&mut self, project: &Project, manifest: &Manifest) -> Result<()> {
+        info!("Running for {} project", &project.name);
+        let backup = manifest
+            .projects
+            .iter()
+            .find(|backup| backup.project_number == project.project_number());
+        let backup = match backup {
+            None => {
+                let message = format!("Backup does not contain {} project", &project.name);
+                warn!("{}", message.yellow());
+                return Ok(());
+            }
+            Some(backup) => backup,
+        };
+        let content = fs::read_to_string(self.from.join(&backup.file))?;
+        let backup_config = serde_json::from_str::<RemoteConfig>(&content).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error {
+                message: format!("Failed to parse backup of {} project.", &project.name),
+            }
+        })?;
+
+        let response = self.network_service.get_remote_config(project).await?;
+        // Texts are compared because the diff ignores condition order and group descriptions
+        let current_text = EditFormat::Json.render(&response.data)?;
+        let backup_text = EditFormat::Json.render(&backup_config)?;
+        if current_text == backup_text {
+            info!("{} project is identical to the backup", &project.name);
+            return Ok(());
+        }
+        println!(
+            "Changes to restore {} project from {}:",
+            &project.name,
+            manifest.created_at.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+        for change in response.data.diff(&backup_config).iter() {
+            println!("  {}", change.summary());
+        }
+        print_diff(&current_text, &backup_text);
+        if !self.input_reader.ask_confirmation("Confirm: [Y,n]") {
+            warn!("Operation was canceled.");
+            return Ok(());
+        }
+        self.network_service
+            .update_remote_config(project, backup_config, response.etag)
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send, E: Editor + Send> Command for RestoreCommand<NS, E> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        let manifest = self.load_manifest()?;
+        self.run(project, &manifest).await
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        let manifest = self.load_manifest()?;
+        for project in projects {
+            if let Err(error) = self.run(project, &manifest).await {
+                error!("{}", error.red());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::backup_command::ManifestProject;
+    use crate::editor::MockEditor;
+    use crate::network::{MockNetworkService, ResponseWithEtag};
+    use crate::remote_config::{Condition, TagColor};
+    use chrono::Utc;
+
+    fn condition(name: &str) -> Condition {
+        Condition {
+            name: name.to_string(),
+            expression: format!("app.userProperty['{}'] == 'true'", name),
+            tag_color: TagColor::Blue,
+        }
+    }
+
+    /// Restores backup over the remote config and checks the number of publishes.
+    async fn restore(
+        test_name: &str,
+        backup_config: RemoteConfig,
+        remote_config: RemoteConfig,
+        update_count: usize,
+    ) {
+        let directory = std::env::temp_dir().join(format!(
+            "rcm-restore-test-{}-{}",
+            std::process::id(),
+            test_name
+        ));
+        fs::create_dir_all(&directory).unwrap();
+        let content = serde_json::to_string(&backup_config).unwrap();
+        fs::write(directory.join("123.json"), content).unwrap();
+        let manifest = Manifest {
+            created_at: Utc::now(),
+            projects: vec![ManifestProject {
+                name: "Test".to_string(),
+                project_number: "123".to_string(),
+                etag: "backup_e_tag".to_string(),
+                file: "123.json".to_string(),
+            }],
+        };
+        let project = Project::new("Test".to_string(), "123".to_string(), vec![]);
+        let mut network_mock = MockNetworkService::new();
+        network_mock
+            .expect_get_remote_config()
+            .times(1)
+            .returning(move |_| {
+                std::result::Result::Ok(ResponseWithEtag {
+                    etag: "e_tag".to_string(),
+                    data: remote_config.clone(),
+                })
+            });
+        network_mock
+            .expect_update_remote_config()
+            .times(update_count)
+            .withf(move |_, config, etag| {
+                etag == "e_tag" && config.conditions[0].name == backup_config.conditions[0].name
+            })
+            .returning(|_, _, _| std::result::Result::Ok(()));
+        let mut editor_mock = MockEditor::new();
+        editor_mock
+            .expect_read_line()
+            .times(update_count)
+            .returning(|| Result::Ok("y".to_string()));
+        let mut command = RestoreCommand::new(
+            directory.clone(),
+            network_mock,
+            InputReader::new(editor_mock),
+        );
+
+        let result = command.run(&project, &manifest).await;
+        let _ = fs::remove_dir_all(&directory);
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn reordered_conditions_are_restored() {
+        let backup_config = RemoteConfig {
+            conditions: vec![condition("beta"), condition("ios")],
+            ..Default::default()
+        };
+        let remote_config = RemoteConfig {
+            conditions: vec![condition("ios"), condition("beta")],
+            ..Default::default()
+        };
+
+        restore("reordered", backup_config, remote_config, 1).await;
+    }
+
+    #[tokio::test]
+    async fn identical_config_is_not_restored() {
+        let backup_config = RemoteConfig {
+            conditions: vec![condition("beta"), condition("ios")],
+            ..Default::default()
+        };
+        let remote_config = backup_config.clone();
+
+        restore("identical", backup_config, remote_config, 0).await;
+    }
+}
@@ -0,0 +1,239 @@
+use super::add_command::expression_builder::PercentExpression;
+use super::add_command::parameter_builder;
+use crate::cli::{ConditionalValue, ParameterValues, Rollout, RolloutSubcommand};
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::editor::Editor;
+use crate::error::{Error, Result};
+use crate::io::InputReader;
+use crate::network::NetworkService;
+use crate::remote_config::{Condition, RemoteConfig, TagColor};
+use async_trait::async_trait;
+use color_eyre::owo_colors::OwoColorize;
+use std::str::FromStr;
+use tracing::{error, info, warn};
+
+pub struct RolloutCommand<NS: NetworkService, E: Editor> {
+    arguments: Rollout,
+    network_service: NS,
+    input_reader: InputReader<E>,
+}
+
+impl<NS: NetworkService, E: Editor> RolloutCommand<NS, E> {
+    pub fn new(arguments: Rollout, network_service: NS, input_reader: InputReader<E>) -> Self {
+        Self {
+            arguments,
+            network_service,
+            input_reader,
+        }
+    }
+
+    async fn run(&mut self, project: &Project) -> Result<()> {
+        info!("Running for {} project", &project.name);
+        let mut response = self.network_service.get_remote_config(project).await?;
+        let remote_config = &mut response.data;
+        let name = self.arguments.parameter_name();
+        let condition_name = self.arguments.condition_name();
+        let group_name = match remote_config.find_parameter(name) {
+            None => {
+                let message = format!("Parameter with name {} does not exists!", name);
+                warn!("{}", message.yellow());
+                return Ok(());
+            }
+            Some((group_name, _)) => group_name.map(String::from),
+        };
+        let previous_expression = remote_config
+            .conditions
+            .iter()
+            .find(|condition| condition.name == condition_name)
+            .map(|condition| condition.expression.clone());
+
+        let expression = match &self.arguments.subcommand {
+            Some(RolloutSubcommand::Bump(arguments)) => {
+                let previous_expression = previous_expression.as_ref().ok_or_else(|| Error {
+                    message: format!("Rollout condition {} does not exist", condition_name),
+                })?;
+                let previous = PercentExpression::from_str(previous_expression)?;
+                if arguments.to < previous.percent {
+                    let message = format!(
+                        "Rollout can only be widened, it is already at {}%",
+                        previous.percent
+                    );
+                    return Err(Error { message });
+                }
+                PercentExpression::new(arguments.to, previous.seed)?
+            }
+            None => {
+                let percent = self.arguments.percent.unwrap();
+                // Existing condition which is not a rollout is never overwritten
+                let previous = previous_expression
+                    .as_deref()
+                    .map(PercentExpression::from_str)
+                    .transpose()
+                    .map_err(|error| Error {
+                        message: format!(
+                            "{}, pass --condition to use another condition",
+                            error.message
+                        ),
+                    })?;
+                let seed = match &self.arguments.seed {
+                    Some(seed) => Some(seed.clone()),
+                    None => previous.and_then(|expression| expression.seed),
+                };
+                PercentExpression::new(percent, seed)?
+            }
+        };
+        let expression = expression.to_string();
+        match &previous_expression {
+            Some(previous_expression) => info!(
+                "Condition {} will be changed from {} to {}",
+                condition_name, previous_expression, expression
+            ),
+            None => info!(
+                "Condition {} will be created with {} expression",
+                condition_name, expression
+            ),
+        }
+        remote_config.set_condition_expression(&condition_name, expression);
+
+        let previous = remote_config.find_parameter(name).unwrap().1.clone();
+        match &self.arguments.value {
+            Some(value) => {
+                remote_config.assign_conditional_value(name, &condition_name, value.clone())?
+            }
+            None if !previous.conditional_values.contains_key(&condition_name) => {
+                let message = format!(
+                    "Parameter {} does not have a value for {} condition, pass --value",
+                    name, condition_name
+                );
+                return Err(Error { message });
+            }
+            None => {}
+        }
+
+        previous.preview(name, "Current value", group_name.as_deref());
+        let (_, parameter) = remote_config.find_parameter(name).unwrap();
+        parameter.preview(name, "New value", group_name.as_deref());
+        if !self.input_reader.ask_confirmation("Confirm: [Y,n]") {
+            warn!("Operation was canceled.");
+            return Ok(());
+        }
+        self.network_service
+            .update_remote_config(project, response.data, response.etag)
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send, E: Editor + Send> Command for RolloutCommand<NS, E> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        self.run(project).await
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        for project in projects {
+            if let Err(error) = self.run(project).await {
+                error!("{}", error.red());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RemoteConfig {
+    /// Replaces expression of existing condition or adds new one with the lowest priority.
+    pub fn set_condition_expression(&mut self, name: &str, expression: String) {
+        match self
+            .conditions
+            .iter_mut()
+            .find(|condition| condition.name == name)
+        {
+            Some(condition) => condition.expression = expression,
+            None => self.conditions.push(Condition {
+                name: name.to_string(),
+                expression,
+                tag_color: TagColor::Orange,
+            }),
+        }
+    }
+
+    /// Validates and sets value for the condition of existing parameter.
+    pub fn assign_conditional_value(
+        &mut self,
+        name: &str,
+        condition_name: &str,
+        value: String,
+    ) -> Result<()> {
+        let values = ParameterValues {
+            conditional: vec![ConditionalValue {
+                condition: condition_name.to_string(),
+                value,
+            }],
+            ..Default::default()
+        };
+        let previous = self.find_parameter(name).map(|(_, parameter)| parameter);
+        let (_, parameter) = parameter_builder::build_from_values(
+            name.to_string(),
+            None,
+            &values,
+            previous,
+            &self.conditions,
+        )?;
+        let map = self.get_map_for_existing_parameter(name).unwrap();
+        map.insert(name.to_string(), parameter);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::editor::MockEditor;
+    use crate::network::{MockNetworkService, ResponseWithEtag};
+    use crate::remote_config::Parameter;
+
+    #[tokio::test]
+    async fn condition_which_is_not_rollout_is_not_overwritten() {
+        let project = Project::new("Test".to_string(), "123".to_string(), vec![]);
+        let mut config = RemoteConfig::default();
+        config
+            .parameters
+            .insert("upload_logs".to_string(), Parameter::stub());
+        config.conditions.push(Condition {
+            name: "upload_logs_rollout".to_string(),
+            expression: "device.os == 'ios'".to_string(),
+            tag_color: TagColor::Blue,
+        });
+        let mut network_mock = MockNetworkService::new();
+        network_mock
+            .expect_get_remote_config()
+            .times(1)
+            .returning(move |_| {
+                std::result::Result::Ok(ResponseWithEtag {
+                    etag: "e_tag".to_string(),
+                    data: config.clone(),
+                })
+            });
+        network_mock.expect_update_remote_config().never();
+        let arguments = Rollout {
+            subcommand: None,
+            name: Some("upload_logs".to_string()),
+            value: Some("true".to_string()),
+            percent: Some(10.0),
+            seed: None,
+            condition: None,
+            yes: true,
+            project: Default::default(),
+        };
+        let mut command =
+            RolloutCommand::new(arguments, network_mock, InputReader::new(MockEditor::new()));
+
+        let error = command.run(&project).await.unwrap_err();
+
+        assert_eq!(
+            error.message,
+            "Expression device.os == 'ios' is not a percentage rollout, pass --condition to use another condition"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use super::add_command::expression_builder::DateTimeExpression;
+use super::add_command::operator::BinaryOperator;
+use crate::cli::Schedule;
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::editor::Editor;
+use crate::error::{Error, Result};
+use crate::io::InputReader;
+use crate::network::NetworkService;
+use async_trait::async_trait;
+use color_eyre::owo_colors::OwoColorize;
+use tracing::{error, info, warn};
+
+pub struct ScheduleCommand<NS: NetworkService, E: Editor> {
+    arguments: Schedule,
+    network_service: NS,
+    input_reader: InputReader<E>,
+}
+
+impl<NS: NetworkService, E: Editor> ScheduleCommand<NS, E> {
+    pub fn new(arguments: Schedule, network_service: NS, input_reader: InputReader<E>) -> Self {
+        Self {
+            arguments,
+            network_service,
+            input_reader,
+        }
+    }
+
+    fn build_expression(&self) -> Result<String> {
+        let time_zone = &self.arguments.time_zone;
+        let start = DateTimeExpression::new(
+            BinaryOperator::MoreEq,
+            &self.arguments.at,
+            time_zone.clone(),
+        )?;
+        let end = match &self.arguments.until {
+            None => return Ok(start.to_string()),
+            Some(until) => DateTimeExpression::new(BinaryOperator::Less, until, time_zone.clone())?,
+        };
+        if start.is_before(&end) == Some(false) {
+            return Err(Error::new("--until must be later than --at"));
+        }
+        Ok(format!("{} && {}", start, end))
+    }
+
+    async fn run(&mut self, project: &Project) -> Result<()> {
+        let expression = self.build_expression()?;
+        info!("Running for {} project", &project.name);
+        let mut response = self.network_service.get_remote_config(project).await?;
+        let remote_config = &mut response.data;
+        let name = &self.arguments.name;
+        let condition_name = self
+            .arguments
+            .condition
+            .clone()
+            .unwrap_or_else(|| format!("{}_schedule", name));
+        let (group_name, previous) = match remote_config.find_parameter(name) {
+            None => {
+                let message = format!("Parameter with name {} does not exists!", name);
+                warn!("{}", message.yellow());
+                return Ok(());
+            }
+            Some((group_name, parameter)) => (group_name.map(String::from), parameter.clone()),
+        };
+
+        info!(
+            "Condition {} will have {} expression",
+            condition_name, expression
+        );
+        remote_config.set_condition_expression(&condition_name, expression);
+        remote_config.assign_conditional_value(
+            name,
+            &condition_name,
+            self.arguments.value.clone(),
+        )?;
+
+        previous.preview(name, "Current value", group_name.as_deref());
+        let (_, parameter) = remote_config.find_parameter(name).unwrap();
+        parameter.preview(name, "New value", group_name.as_deref());
+        if !self.input_reader.ask_confirmation("Confirm: [Y,n]") {
+            warn!("Operation was canceled.");
+            return Ok(());
+        }
+        self.network_service
+            .update_remote_config(project, response.data, response.etag)
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send, E: Editor + Send> Command for ScheduleCommand<NS, E> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        self.run(project).await
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        for project in projects {
+            if let Err(error) = self.run(project).await {
+                error!("{}", error.red());
+            }
+        }
+        Ok(())
+    }
+}
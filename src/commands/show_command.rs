@@ -1,18 +1,80 @@
+use super::output::{self, Records};
+use super::remote_config_table::build_matrix_table;
+use crate::cli::{OutputFormat, ShowFilter};
 use crate::commands::command::Command;
 use crate::config::Project;
 use crate::error::Result;
 use crate::network::NetworkService;
+use crate::remote_config::RemoteConfig;
 use async_trait::async_trait;
 use color_eyre::owo_colors::OwoColorize;
-use tracing::{error, info};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use tracing::{error, info, warn};
 
 pub struct ShowCommand<NS: NetworkService> {
+    filter: ShowFilter,
+    is_matrix: bool,
+    output: OutputFormat,
     network_service: NS,
 }
 
+#[derive(Serialize)]
+struct ProjectRemoteConfig<'a> {
+    project: &'a str,
+    #[serde(flatten)]
+    config: RemoteConfig,
+}
+
 impl<NS: NetworkService> ShowCommand<NS> {
-    pub fn new(network_service: NS) -> Self {
-        Self { network_service }
+    pub fn new(
+        filter: ShowFilter,
+        is_matrix: bool,
+        output: OutputFormat,
+        network_service: NS,
+    ) -> Self {
+        Self {
+            filter,
+            is_matrix,
+            output,
+            network_service,
+        }
+    }
+
+    fn print_structured(&self, configs: &[(&Project, RemoteConfig)]) -> Result<()> {
+        let configs = configs
+            .iter()
+            .map(|(project, config)| ProjectRemoteConfig {
+                project: &project.name,
+                config: config.filtered(&self.filter),
+            })
+            .collect::<Vec<_>>();
+        let make_records = || {
+            if self.filter.conditions_only {
+                ProjectRemoteConfig::condition_records(&configs)
+            } else {
+                ProjectRemoteConfig::parameter_records(&configs)
+            }
+        };
+        output::print_structured(&configs, make_records, self.output)
+    }
+
+    fn render_matrix(&self, configs: &[(&Project, RemoteConfig)]) {
+        let names = configs
+            .iter()
+            .flat_map(|(_, config)| config.matching_parameter_names(&self.filter))
+            .collect::<BTreeSet<_>>();
+        if names.is_empty() {
+            warn!("{}", "No parameters matching filters were found.".yellow());
+            return;
+        }
+        for name in names {
+            let entries = configs
+                .iter()
+                .map(|(project, config)| (project.name.as_str(), config.find_parameter(name)))
+                .collect::<Vec<_>>();
+            println!("{}", build_matrix_table(name, &entries).render());
+        }
     }
 }
 
@@ -21,24 +83,130 @@ impl<NS: NetworkService + Send> Command for ShowCommand<NS> {
     async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
         info!("Running for {} project", &project.name);
         let response = self.network_service.get_remote_config(project).await?;
-        let table = response.data.build_table(&project.name);
+        if self.is_matrix {
+            self.render_matrix(&[(project, response.data)]);
+            return Ok(());
+        }
+        if self.output != OutputFormat::Table {
+            return self.print_structured(&[(project, response.data)]);
+        }
+        let table = response.data.build_table(&project.name, &self.filter);
         println!("{}", table.render());
         Ok(())
     }
 
     async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        let mut configs = Vec::with_capacity(projects.len());
         for project in projects {
             info!("Running for {} project", &project.name);
             match self.network_service.get_remote_config(project).await {
                 Err(error) => {
                     error!("{}", error.to_string().red());
                 }
+                Ok(response) if self.is_matrix || self.output != OutputFormat::Table => {
+                    configs.push((project, response.data))
+                }
                 Ok(response) => {
-                    let table = response.data.build_table(&project.name);
+                    let table = response.data.build_table(&project.name, &self.filter);
                     println!("{}", table.render());
                 }
             }
         }
+        if self.is_matrix {
+            self.render_matrix(&configs);
+        } else if self.output != OutputFormat::Table {
+            self.print_structured(&configs)?;
+        }
         Ok(())
     }
 }
+
+impl RemoteConfig {
+    fn matching_parameter_names<'a>(&'a self, filter: &'a ShowFilter) -> Vec<&'a str> {
+        let root_parameters = self
+            .parameters
+            .iter()
+            .filter(|(name, parameter)| filter.is_parameter_included(name, None, parameter))
+            .map(|(name, _)| name.as_str());
+        let group_parameters = self
+            .parameter_groups
+            .iter()
+            .flat_map(|(group_name, group)| {
+                group
+                    .parameters
+                    .iter()
+                    .filter(|(name, parameter)| {
+                        filter.is_parameter_included(name, Some(group_name), parameter)
+                    })
+                    .map(|(name, _)| name.as_str())
+            });
+        root_parameters.chain(group_parameters).collect()
+    }
+}
+
+impl<'a> ProjectRemoteConfig<'a> {
+    fn parameter_records(configs: &[ProjectRemoteConfig]) -> Records {
+        let mut records = Records::new(&[
+            "project",
+            "parameter",
+            "group",
+            "value_type",
+            "condition",
+            "value",
+        ]);
+        for project_config in configs {
+            let config = &project_config.config;
+            let root_parameters = config
+                .parameters
+                .iter()
+                .map(|(name, parameter)| (name, "", parameter));
+            let group_parameters =
+                config
+                    .parameter_groups
+                    .iter()
+                    .flat_map(|(group_name, group)| {
+                        group
+                            .parameters
+                            .iter()
+                            .map(move |(name, parameter)| (name, group_name.as_str(), parameter))
+                    });
+            for (name, group_name, parameter) in root_parameters.chain(group_parameters) {
+                let values = parameter
+                    .default_value
+                    .iter()
+                    .map(|value| ("", value))
+                    .chain(
+                        parameter
+                            .conditional_values
+                            .iter()
+                            .map(|(condition, value)| (condition.as_str(), value)),
+                    );
+                for (condition, value) in values {
+                    records.push(vec![
+                        project_config.project.to_string(),
+                        name.clone(),
+                        group_name.to_string(),
+                        parameter.value_type.cell_content().to_string(),
+                        condition.to_string(),
+                        value.cell_content().to_string(),
+                    ]);
+                }
+            }
+        }
+        records
+    }
+
+    fn condition_records(configs: &[ProjectRemoteConfig]) -> Records {
+        let mut records = Records::new(&["project", "condition", "expression"]);
+        for project_config in configs {
+            for condition in project_config.config.conditions.iter() {
+                records.push(vec![
+                    project_config.project.to_string(),
+                    condition.name.clone(),
+                    condition.expression.clone(),
+                ]);
+            }
+        }
+        records
+    }
+}
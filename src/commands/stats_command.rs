@@ -0,0 +1,320 @@
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::error::Result;
+use crate::network::NetworkService;
+use crate::remote_config::{ParameterValue, ParameterValueType, RemoteConfig};
+use async_trait::async_trait;
+use color_eyre::owo_colors::OwoColorize;
+use std::collections::{BTreeMap, HashMap};
+use term_table::row::Row;
+use term_table::table_cell::{Alignment, TableCell};
+use term_table::{Table, TableStyle};
+use tracing::{error, info, warn};
+
+const MAX_PARAMETERS: usize = 3000;
+const MAX_CONDITIONS: usize = 2000;
+const MAX_TEMPLATE_SIZE: usize = 1_000_000;
+/// Share of the limit after which warning is shown.
+const WARNING_RATIO: f64 = 0.8;
+const LARGEST_VALUES_COUNT: usize = 5;
+const ROOT_GROUP_NAME: &str = "(root)";
+
+pub struct StatsCommand<NS: NetworkService> {
+    network_service: NS,
+}
+
+struct Stats {
+    parameter_count: usize,
+    condition_count: usize,
+    template_size: usize,
+    by_type: BTreeMap<String, usize>,
+    by_group: BTreeMap<String, usize>,
+    /// Parameter name, value label and size in bytes, largest first
+    largest_values: Vec<(String, String, usize)>,
+    /// Condition name and number of parameters using it, most used first
+    condition_uses: Vec<(String, usize)>,
+}
+
+struct Limit {
+    name: &'static str,
+    value: usize,
+    max: usize,
+}
+
+impl<NS: NetworkService> StatsCommand<NS> {
+    pub fn new(network_service: NS) -> Self {
+        Self { network_service }
+    }
+
+    async fn run(&mut self, project: &Project) -> Result<()> {
+        info!("Running for {} project", &project.name);
+        let response = self.network_service.get_remote_config(project).await?;
+        let stats = response.data.stats();
+        println!("{}", stats.make_table(&project.name).render());
+        for limit in stats.limits() {
+            let ratio = limit.ratio();
+            let message = format!(
+                "{} project uses {:.0}% of {} limit ({} of {})",
+                &project.name,
+                ratio * 100.0,
+                limit.name,
+                limit.value,
+                limit.max
+            );
+            if ratio >= 1.0 {
+                error!("{}", message.red());
+            } else if ratio >= WARNING_RATIO {
+                warn!("{}", message.yellow());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send> Command for StatsCommand<NS> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        self.run(project).await
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        for project in projects {
+            if let Err(error) = self.run(project).await {
+                error!("{}", error.red());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RemoteConfig {
+    fn stats(&self) -> Stats {
+        let grouped_parameters =
+            self.parameters
+                .iter()
+                .map(|(name, parameter)| (ROOT_GROUP_NAME, name, parameter))
+                .chain(
+                    self.parameter_groups
+                        .iter()
+                        .flat_map(|(group_name, group)| {
+                            group.parameters.iter().map(move |(name, parameter)| {
+                                (group_name.as_str(), name, parameter)
+                            })
+                        }),
+                )
+                .collect::<Vec<_>>();
+
+        let mut by_type = BTreeMap::new();
+        let mut by_group = BTreeMap::new();
+        let mut condition_uses = self
+            .conditions
+            .iter()
+            .map(|condition| (condition.name.as_str(), 0))
+            .collect::<HashMap<_, _>>();
+        let mut largest_values = Vec::new();
+        for (group_name, name, parameter) in grouped_parameters.iter() {
+            let value_type = match parameter.value_type {
+                ParameterValueType::Unspecified => "UNSPECIFIED",
+                ParameterValueType::Boolean => "BOOLEAN",
+                ParameterValueType::String => "STRING",
+                ParameterValueType::Number => "NUMBER",
+                ParameterValueType::Json => "JSON",
+            };
+            *by_type.entry(value_type.to_string()).or_insert(0) += 1;
+            *by_group.entry(group_name.to_string()).or_insert(0) += 1;
+            for condition in parameter.conditional_values.keys() {
+                *condition_uses.entry(condition.as_str()).or_insert(0) += 1;
+            }
+            let values = parameter
+                .default_value
+                .iter()
+                .map(|value| ("default".to_string(), value))
+                .chain(
+                    parameter
+                        .conditional_values
+                        .iter()
+                        .map(|(condition, value)| (condition.clone(), value)),
+                );
+            for (label, value) in values {
+                if let ParameterValue::Value(value) = value {
+                    largest_values.push((name.to_string(), label, value.len()));
+                }
+            }
+        }
+        largest_values.sort_by(|lhs, rhs| rhs.2.cmp(&lhs.2).then_with(|| lhs.cmp(rhs)));
+        largest_values.truncate(LARGEST_VALUES_COUNT);
+        let mut condition_uses = condition_uses
+            .into_iter()
+            .map(|(name, count)| (name.to_string(), count))
+            .collect::<Vec<_>>();
+        condition_uses.sort_by(|lhs, rhs| rhs.1.cmp(&lhs.1).then_with(|| lhs.0.cmp(&rhs.0)));
+
+        Stats {
+            parameter_count: grouped_parameters.len(),
+            condition_count: self.conditions.len(),
+            template_size: serde_json::to_vec(self)
+                .map(|bytes| bytes.len())
+                .unwrap_or_default(),
+            by_type,
+            by_group,
+            largest_values,
+            condition_uses,
+        }
+    }
+}
+
+impl Stats {
+    fn limits(&self) -> [Limit; 3] {
+        [
+            Limit {
+                name: "parameters",
+                value: self.parameter_count,
+                max: MAX_PARAMETERS,
+            },
+            Limit {
+                name: "conditions",
+                value: self.condition_count,
+                max: MAX_CONDITIONS,
+            },
+            Limit {
+                name: "template size in bytes",
+                value: self.template_size,
+                max: MAX_TEMPLATE_SIZE,
+            },
+        ]
+    }
+
+    fn make_table(&self, project_name: &str) -> Table<'_> {
+        let mut table = Table::new();
+        table.style = TableStyle::simple();
+        table.max_column_width = 40;
+        let title = format!("Stats of {} project", project_name);
+        table.add_row(Row::new(vec![TableCell::new_with_alignment(
+            title,
+            3,
+            Alignment::Center,
+        )]));
+
+        Self::add_section(&mut table, "Limits");
+        for limit in self.limits() {
+            let usage = format!("{:.1}%", limit.ratio() * 100.0);
+            let usage = if limit.ratio() >= 1.0 {
+                usage.red().to_string()
+            } else if limit.ratio() >= WARNING_RATIO {
+                usage.yellow().to_string()
+            } else {
+                usage
+            };
+            table.add_row(Row::new(vec![
+                TableCell::new(limit.name),
+                TableCell::new(format!("{} of {}", limit.value, limit.max)),
+                TableCell::new(usage),
+            ]));
+        }
+
+        Self::add_section(&mut table, "Parameters by type");
+        for (value_type, count) in self.by_type.iter() {
+            table.add_row(Row::new(vec![
+                TableCell::new_with_col_span(value_type, 2),
+                TableCell::new(count),
+            ]));
+        }
+
+        Self::add_section(&mut table, "Parameters by group");
+        for (group_name, count) in self.by_group.iter() {
+            table.add_row(Row::new(vec![
+                TableCell::new_with_col_span(group_name, 2),
+                TableCell::new(count),
+            ]));
+        }
+
+        if !self.largest_values.is_empty() {
+            Self::add_section(&mut table, "Largest values, bytes");
+            for (name, label, size) in self.largest_values.iter() {
+                table.add_row(Row::new(vec![
+                    TableCell::new(name),
+                    TableCell::new(label),
+                    TableCell::new(size),
+                ]));
+            }
+        }
+
+        if !self.condition_uses.is_empty() {
+            Self::add_section(&mut table, "Conditions by uses");
+            for (name, count) in self.condition_uses.iter() {
+                table.add_row(Row::new(vec![
+                    TableCell::new_with_col_span(name, 2),
+                    TableCell::new(count),
+                ]));
+            }
+        }
+        table
+    }
+
+    fn add_section(table: &mut Table, title: &str) {
+        table.add_row(Row::new(vec![TableCell::new_with_alignment(
+            title.bold(),
+            3,
+            Alignment::Center,
+        )]));
+    }
+}
+
+impl Limit {
+    fn ratio(&self) -> f64 {
+        self.value as f64 / self.max as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::remote_config::{Condition, Parameter, ParameterGroup, TagColor};
+
+    #[test]
+    fn stats_count_grouped_parameters_and_condition_uses() {
+        let mut config = RemoteConfig::default();
+        for name in ["iOS", "Beta"] {
+            config.conditions.push(Condition {
+                name: name.to_string(),
+                expression: "true".to_string(),
+                tag_color: TagColor::Blue,
+            });
+        }
+        let mut parameter = Parameter::stub();
+        parameter.conditional_values =
+            HashMap::from([("iOS".to_string(), ParameterValue::Value("true".to_string()))]);
+        config
+            .parameters
+            .insert("upload_logs".to_string(), parameter.clone());
+        parameter.value_type = ParameterValueType::Json;
+        parameter.default_value = Some(ParameterValue::Value(r#"{"items": []}"#.to_string()));
+        let group = ParameterGroup {
+            description: None,
+            parameters: HashMap::from([("checkout".to_string(), parameter)]),
+        };
+        config
+            .parameter_groups
+            .insert("Checkout".to_string(), group);
+
+        let stats = config.stats();
+
+        assert_eq!(stats.parameter_count, 2);
+        assert_eq!(stats.by_type["BOOLEAN"], 1);
+        assert_eq!(stats.by_type["JSON"], 1);
+        assert_eq!(stats.by_group[ROOT_GROUP_NAME], 1);
+        assert_eq!(stats.by_group["Checkout"], 1);
+        assert_eq!(
+            stats.largest_values[0],
+            ("checkout".to_string(), "default".to_string(), 13)
+        );
+        assert_eq!(
+            stats.condition_uses,
+            vec![("iOS".to_string(), 2), ("Beta".to_string(), 0)]
+        );
+        assert_eq!(
+            stats.limits()[2].value,
+            serde_json::to_vec(&config).unwrap().len()
+        );
+    }
+}
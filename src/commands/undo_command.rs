@@ -0,0 +1,186 @@
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::editor::Editor;
+use crate::error::{Error, Result};
+use crate::io::InputReader;
+use crate::network::NetworkService;
+use crate::snapshot::SnapshotStore;
+use async_trait::async_trait;
+use color_eyre::owo_colors::OwoColorize;
+use tracing::{error, info, warn};
+
+pub struct UndoCommand<NS: NetworkService, E: Editor> {
+    steps: usize,
+    snapshot_store: SnapshotStore,
+    network_service: NS,
+    input_reader: InputReader<E>,
+}
+
+impl<NS: NetworkService, E: Editor> UndoCommand<NS, E> {
+    pub fn new(
+        steps: usize,
+        app_name: String,
+        network_service: NS,
+        input_reader: InputReader<E>,
+    ) -> Self {
+        Self {
+            steps,
+            snapshot_store: SnapshotStore::new(app_name),
+            network_service,
+            input_reader,
+        }
+    }
+
+    async fn run(&mut self, project: &Project) -> Result<()> {
+        info!("Running for {} project", &project.name);
+        let mut snapshots = self.snapshot_store.list(project.project_number())?;
+        if snapshots.len() < self.steps {
+            let message = format!(
+                "Only {} change(s) can be undone in {} project",
+                snapshots.len(),
+                &project.name
+            );
+            return Err(Error { message });
+        }
+        let snapshots = snapshots.split_off(snapshots.len() - self.steps);
+
+        let mut response = self.network_service.get_remote_config(project).await?;
+        let mut remote_config = response.data.clone();
+        for (_, snapshot) in snapshots.iter().rev() {
+            info!(
+                "Reverting change published at {}",
+                snapshot.timestamp.format("%Y-%m-%d %H:%M:%S UTC")
+            );
+            let conflicts = remote_config.revert(&snapshot.before, &snapshot.after);
+            for conflict in conflicts {
+                let message = format!(
+                    "Skipping {}, it was changed after rcm published it",
+                    conflict.summary()
+                );
+                warn!("{}", message.yellow());
+            }
+        }
+
+        let changes = response.data.diff(&remote_config);
+        if changes.is_empty() {
+            info!("There are no changes to undo in {} project", &project.name);
+            return Ok(());
+        }
+        println!("Changes to undo in {} project:", &project.name);
+        for change in changes.iter() {
+            println!("  {}", change.summary());
+        }
+        if !self.input_reader.ask_confirmation("Confirm: [Y,n]") {
+            warn!("Operation was canceled.");
+            return Ok(());
+        }
+        response.data = remote_config;
+        self.network_service
+            .revert_remote_config(project, response.data, response.etag)
+            .await?;
+        for (path, _) in snapshots.iter() {
+            self.snapshot_store.remove(path)?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send, E: Editor + Send> Command for UndoCommand<NS, E> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        self.run(project).await
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        for project in projects {
+            if let Err(error) = self.run(project).await {
+                error!("{}", error.red());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::editor::MockEditor;
+    use crate::network::{MockNetworkService, ResponseWithEtag};
+    use crate::remote_config::{Parameter, ParameterValue, RemoteConfig};
+    use crate::snapshot::Snapshot;
+    use chrono::{Duration, Utc};
+    use std::fs;
+    use std::sync::{Arc, Mutex};
+
+    fn config_with_value(value: &str) -> RemoteConfig {
+        let mut parameter = Parameter::stub();
+        parameter.default_value = Some(ParameterValue::Value(value.to_string()));
+        let mut config = RemoteConfig::default();
+        config.parameters.insert("timeout".to_string(), parameter);
+        config
+    }
+
+    #[tokio::test]
+    async fn repeated_undo_walks_back_through_changes() {
+        let root_directory =
+            std::env::temp_dir().join(format!("rcm-undo-test-{}", std::process::id()));
+        let project = Project::new("Test".to_string(), "123".to_string(), vec![]);
+        let snapshot_store = SnapshotStore::with_root_directory(root_directory.clone());
+        let configs = ["1", "2", "3"].map(config_with_value);
+        let now = Utc::now();
+        for (index, window) in configs.windows(2).enumerate() {
+            let snapshot = Snapshot {
+                timestamp: now + Duration::seconds(index as i64),
+                project: project.name.clone(),
+                previous_etag: index.to_string(),
+                before: window[0].clone(),
+                after: window[1].clone(),
+            };
+            snapshot_store
+                .save(project.project_number(), &snapshot)
+                .unwrap();
+        }
+
+        let current_config = Arc::new(Mutex::new(configs[2].clone()));
+        let mut network_mock = MockNetworkService::new();
+        let fetched_config = current_config.clone();
+        network_mock
+            .expect_get_remote_config()
+            .times(2)
+            .returning(move |_| {
+                std::result::Result::Ok(ResponseWithEtag {
+                    etag: "e_tag".to_string(),
+                    data: fetched_config.lock().unwrap().clone(),
+                })
+            });
+        network_mock.expect_update_remote_config().never();
+        let published_config = current_config.clone();
+        network_mock
+            .expect_revert_remote_config()
+            .times(2)
+            .returning(move |_, config, _| {
+                *published_config.lock().unwrap() = config;
+                std::result::Result::Ok(())
+            });
+        let mut editor_mock = MockEditor::new();
+        editor_mock
+            .expect_read_line()
+            .times(2)
+            .returning(|| Result::Ok("y".to_string()));
+
+        let mut command = UndoCommand {
+            steps: 1,
+            snapshot_store,
+            network_service: network_mock,
+            input_reader: InputReader::new(editor_mock),
+        };
+        command.run(&project).await.unwrap();
+        assert_eq!(*current_config.lock().unwrap(), configs[1]);
+        command.run(&project).await.unwrap();
+        assert_eq!(*current_config.lock().unwrap(), configs[0]);
+
+        let remaining_snapshots = command.snapshot_store.list(project.project_number());
+        let _ = fs::remove_dir_all(&root_directory);
+        assert!(remaining_snapshots.unwrap().is_empty());
+    }
+}
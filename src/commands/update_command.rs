@@ -1,12 +1,14 @@
-use super::add_command::parameter_builder::ParameterBuilder;
+use super::add_command::parameter_builder::{self, ParameterBuilder};
 use super::add_command::{Action, AddCommand};
+use crate::cli::ParameterValues;
 use crate::commands::command::Command;
 use crate::config::Project;
 use crate::editor::Editor;
 use crate::error::{Error, Result};
 use crate::io::InputReader;
 use crate::network::{NetworkService, ResponseWithEtag};
-use crate::remote_config::{Parameter, RemoteConfig};
+use crate::remote_config::{Condition, Parameter, ParameterGroup, RemoteConfig};
+use crate::schema::Schemas;
 use async_trait::async_trait;
 use color_eyre::owo_colors::OwoColorize;
 use std::collections::HashMap;
@@ -14,14 +16,24 @@ use tracing::{info, warn};
 
 pub struct UpdateCommand<NS: NetworkService, E: Editor> {
     name: String,
+    values: Option<ParameterValues>,
+    schemas: Schemas,
     network_service: Option<NS>,
     input_reader: Option<InputReader<E>>,
 }
 
 impl<NS: NetworkService, E: Editor> UpdateCommand<NS, E> {
-    pub fn new(name: String, network_service: NS, input_reader: InputReader<E>) -> Self {
+    pub fn new(
+        name: String,
+        values: ParameterValues,
+        schemas: Schemas,
+        network_service: NS,
+        input_reader: InputReader<E>,
+    ) -> Self {
         Self {
             name,
+            values: Some(values),
+            schemas,
             network_service: Some(network_service),
             input_reader: Some(input_reader),
         }
@@ -50,6 +62,32 @@ impl<NS: NetworkService, E: Editor> UpdateCommand<NS, E> {
         }
     }
 
+    fn build_parameter(
+        &mut self,
+        previous: &Parameter,
+        app_ids: &[String],
+        conditions: &mut Vec<Condition>,
+    ) -> Result<(String, Parameter)> {
+        let values = self.values.as_ref().unwrap();
+        if values.has_values() {
+            return parameter_builder::build_from_values(
+                std::mem::take(&mut self.name),
+                None,
+                values,
+                Some(previous),
+                conditions,
+            );
+        }
+        let parameter = ParameterBuilder::start_flow(
+            Some(std::mem::take(&mut self.name)),
+            previous.description.clone(),
+            self.input_reader.as_mut().unwrap(),
+            app_ids,
+            conditions,
+        );
+        Ok(parameter)
+    }
+
     async fn update_parameter(
         &mut self,
         name: String,
@@ -58,6 +96,7 @@ impl<NS: NetworkService, E: Editor> UpdateCommand<NS, E> {
         source: &ParameterSource,
         project: &Project,
     ) -> Result<()> {
+        self.schemas.check(&name, &parameter)?;
         match source {
             ParameterSource::Root => {
                 parameter.preview(&self.name, "Parameter will be updated", None)
@@ -74,8 +113,28 @@ impl<NS: NetworkService, E: Editor> UpdateCommand<NS, E> {
         {
             return Ok(());
         }
-        let params = response.data.find_source_params(source);
-        params.insert(name, parameter);
+        let group_name = self.values.as_ref().unwrap().group.clone();
+        match group_name {
+            Some(group_name) => {
+                response.data.find_source_params(source).remove(&name);
+                response
+                    .data
+                    .parameter_groups
+                    .entry(group_name)
+                    .or_insert_with(|| ParameterGroup {
+                        description: None,
+                        parameters: HashMap::new(),
+                    })
+                    .parameters
+                    .insert(name, parameter);
+            }
+            None => {
+                response
+                    .data
+                    .find_source_params(source)
+                    .insert(name, parameter);
+            }
+        }
         self.network_service
             .as_mut()
             .unwrap()
@@ -98,14 +157,9 @@ impl<NS: NetworkService + Send, E: Editor + Send> Command for UpdateCommand<NS,
             return Ok(());
         }
         let (source, parameter) = source.unwrap();
-        let description = parameter.description.clone();
-        let (name, parameter) = ParameterBuilder::start_flow(
-            Some(std::mem::take(&mut self.name)),
-            description,
-            self.input_reader.as_mut().unwrap(),
-            &project.app_ids,
-            &mut config.conditions,
-        );
+        let parameter = parameter.clone();
+        let (name, parameter) =
+            self.build_parameter(&parameter, &project.app_ids, &mut config.conditions)?;
         self.update_parameter(name, parameter, response, &source, project)
             .await
     }
@@ -122,18 +176,18 @@ impl<NS: NetworkService + Send, E: Editor + Send> Command for UpdateCommand<NS,
             return Ok(());
         }
         let (_, parameter) = source.unwrap();
-        let description = parameter.description.clone();
-        let (name, parameter) = ParameterBuilder::start_flow(
-            Some(std::mem::take(&mut self.name)),
-            description,
-            self.input_reader.as_mut().unwrap(),
+        let parameter = parameter.clone();
+        let (name, parameter) = self.build_parameter(
+            &parameter,
             &main_project.app_ids,
             &mut response.data.conditions,
-        );
+        )?;
 
         let mut add_command = AddCommand::new(
             None,
             None,
+            self.values.take().unwrap(),
+            std::mem::take(&mut self.schemas),
             self.network_service.take().unwrap(),
             self.input_reader.take().unwrap(),
         );
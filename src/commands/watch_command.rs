@@ -0,0 +1,214 @@
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::diff::Change;
+use crate::error::{Error, Result};
+use crate::network::NetworkService;
+use crate::remote_config::RemoteConfig;
+use async_trait::async_trait;
+use color_eyre::owo_colors::OwoColorize;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tracing::{debug, error, info};
+
+pub struct WatchCommand<NS: NetworkService> {
+    interval: Duration,
+    exec: Option<String>,
+    network_service: NS,
+    /// Last seen ETag and config by project number
+    last_seen: HashMap<String, (String, RemoteConfig)>,
+}
+
+/// Payload passed to the user command.
+#[derive(Serialize)]
+struct RemoteChange<'a> {
+    project: &'a str,
+    previous_etag: &'a str,
+    etag: &'a str,
+    changes: &'a [Change],
+}
+
+impl<NS: NetworkService> WatchCommand<NS> {
+    pub fn new(interval: Duration, exec: Option<String>, network_service: NS) -> Self {
+        Self {
+            interval,
+            exec,
+            network_service,
+            last_seen: HashMap::new(),
+        }
+    }
+
+    async fn run(&mut self, projects: &[Project]) -> Result<()> {
+        info!(
+            "Watching {} project(s) every {} seconds",
+            projects.len(),
+            self.interval.as_secs()
+        );
+        loop {
+            for project in projects {
+                if let Err(error) = self.poll(project).await {
+                    error!("{}", error.red());
+                }
+            }
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+
+    async fn poll(&mut self, project: &Project) -> Result<()> {
+        let response = self.network_service.get_remote_config(project).await?;
+        let key = project.project_number().to_string();
+        let (previous_etag, previous_config) = match self.last_seen.remove(&key) {
+            None => {
+                info!("{} project has {} version", &project.name, &response.etag);
+                self.last_seen.insert(key, (response.etag, response.data));
+                return Ok(());
+            }
+            Some(last_seen) => last_seen,
+        };
+        if previous_etag == response.etag {
+            self.last_seen.insert(key, (response.etag, response.data));
+            return Ok(());
+        }
+        let changes = previous_config.diff(&response.data);
+        print_changes(&project.name, &changes);
+        // New version is stored before the command runs, so its failure does not reset the state
+        let etag = response.etag.clone();
+        self.last_seen.insert(key, (response.etag, response.data));
+        if let Some(exec) = self.exec.as_ref() {
+            let change = RemoteChange {
+                project: &project.name,
+                previous_etag: &previous_etag,
+                etag: &etag,
+                changes: &changes,
+            };
+            run_user_command(exec, &change).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send> Command for WatchCommand<NS> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        self.run(std::slice::from_ref(project)).await
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        self.run(projects).await
+    }
+}
+
+fn print_changes(project_name: &str, changes: &[Change]) {
+    let title = format!("{} project was changed", project_name);
+    println!("{}", title.bold());
+    for change in changes {
+        let summary = change.summary();
+        match change {
+            Change::Parameter {
+                before: None,
+                after: Some(_),
+                ..
+            }
+            | Change::Condition {
+                before: None,
+                after: Some(_),
+                ..
+            } => println!("  {}", summary.green()),
+            Change::Parameter { after: None, .. } | Change::Condition { after: None, .. } => {
+                println!("  {}", summary.red())
+            }
+            _ => println!("  {}", summary.yellow()),
+        }
+    }
+    if changes.is_empty() {
+        println!("  Version changed without changes in parameters and conditions");
+    }
+}
+
+/// Runs `command` in the shell and writes the change as JSON to its stdin.
+async fn run_user_command(command: &str, change: &RemoteChange<'_>) -> Result<()> {
+    let json = serde_json::to_string(change).map_err(|error| {
+        debug!("Error: {:?}", error);
+        Error::new("Failed to serialize changes.")
+    })?;
+    let mut child = if cfg!(windows) {
+        tokio::process::Command::new("cmd")
+            .args(["/C", command])
+            .stdin(Stdio::piped())
+            .spawn()?
+    } else {
+        tokio::process::Command::new("sh")
+            .args(["-c", command])
+            .stdin(Stdio::piped())
+            .spawn()?
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        // Commands are not required to read the changes, so closed stdin is not an error
+        match stdin.write_all(json.as_bytes()).await {
+            Err(error) if error.kind() != std::io::ErrorKind::BrokenPipe => {
+                return Err(error.into())
+            }
+            _ => {}
+        }
+    }
+    let status = child.wait().await?;
+    if !status.success() {
+        return Err(Error {
+            message: format!("Command {} failed with {}", command, status),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::{MockNetworkService, ResponseWithEtag};
+    use crate::remote_config::Parameter;
+
+    #[tokio::test]
+    async fn changes_are_detected_after_failed_command() {
+        let project = Project::new("Test".to_string(), "123".to_string(), vec![]);
+        let mut changed_config = RemoteConfig::default();
+        changed_config
+            .parameters
+            .insert("upload_logs".to_string(), Parameter::stub());
+        let responses = [
+            ("e_tag_1", RemoteConfig::default()),
+            ("e_tag_1", RemoteConfig::default()),
+            ("e_tag_2", changed_config),
+            ("e_tag_2", RemoteConfig::default()),
+        ];
+        let mut network_mock = MockNetworkService::new();
+        let mut index = 0;
+        network_mock
+            .expect_get_remote_config()
+            .times(responses.len())
+            .returning(move |_| {
+                let (etag, config) = responses[index].clone();
+                index += 1;
+                std::result::Result::Ok(ResponseWithEtag {
+                    etag: etag.to_string(),
+                    data: config,
+                })
+            });
+        let mut command = WatchCommand::new(
+            Duration::from_secs(1),
+            Some("exit 1".to_string()),
+            network_mock,
+        );
+        let last_etag = |command: &WatchCommand<MockNetworkService>| {
+            command.last_seen[project.project_number()].0.clone()
+        };
+
+        assert!(command.poll(&project).await.is_ok());
+        assert_eq!(last_etag(&command), "e_tag_1");
+        assert!(command.poll(&project).await.is_ok());
+        let error = command.poll(&project).await.unwrap_err();
+        assert!(error.message.starts_with("Command exit 1 failed"));
+        assert_eq!(last_etag(&command), "e_tag_2");
+        assert!(command.poll(&project).await.is_ok());
+    }
+}
@@ -0,0 +1,308 @@
+use crate::commands::command::Command;
+use crate::config::Project;
+use crate::error::Result;
+use crate::network::NetworkService;
+use crate::remote_config::RemoteConfig;
+use async_trait::async_trait;
+use color_eyre::owo_colors::OwoColorize;
+use term_table::row::Row;
+use term_table::table_cell::{Alignment, TableCell};
+use term_table::{Table, TableStyle};
+use tracing::{error, info, warn};
+
+pub struct WhereUsedCommand<NS: NetworkService> {
+    condition: Option<String>,
+    name: Option<String>,
+    network_service: NS,
+}
+
+/// Parameter with conditional value for the condition.
+#[derive(Debug, PartialEq)]
+struct ConditionUsage {
+    parameter: String,
+    group: Option<String>,
+    value: String,
+}
+
+/// Conditional value of the parameter, ordered by condition priority.
+#[derive(Debug, PartialEq)]
+struct ParameterCondition {
+    priority: usize,
+    condition: String,
+    expression: String,
+    value: String,
+}
+
+enum Usages {
+    Condition(Vec<ConditionUsage>),
+    Parameter(Vec<ParameterCondition>),
+}
+
+impl<NS: NetworkService> WhereUsedCommand<NS> {
+    pub fn new(condition: Option<String>, name: Option<String>, network_service: NS) -> Self {
+        Self {
+            condition,
+            name,
+            network_service,
+        }
+    }
+
+    async fn run(&mut self, projects: &[Project]) -> Result<()> {
+        let mut usages = Vec::with_capacity(projects.len());
+        for project in projects {
+            info!("Running for {} project", &project.name);
+            match self.find_usages(project).await {
+                Ok(Some(project_usages)) => usages.push((project, project_usages)),
+                Ok(None) => {}
+                Err(error) => error!("{}", error.red()),
+            }
+        }
+        if usages.is_empty() {
+            return Ok(());
+        }
+
+        let mut table = Table::new();
+        table.style = TableStyle::simple();
+        table.max_column_width = 40;
+        for (project, project_usages) in usages.iter() {
+            match project_usages {
+                Usages::Condition(usages) => {
+                    let condition = self.condition.as_deref().unwrap_or_default();
+                    Self::add_condition_rows(&mut table, &project.name, condition, usages)
+                }
+                Usages::Parameter(conditions) => {
+                    let name = self.name.as_deref().unwrap_or_default();
+                    Self::add_parameter_rows(&mut table, &project.name, name, conditions)
+                }
+            }
+        }
+        println!("{}", table.render());
+        Ok(())
+    }
+
+    /// Returns `None` if the condition or the parameter does not exist in the project.
+    async fn find_usages(&mut self, project: &Project) -> Result<Option<Usages>> {
+        let response = self.network_service.get_remote_config(project).await?;
+        let remote_config = &response.data;
+        if let Some(condition) = self.condition.as_ref() {
+            if !remote_config
+                .conditions
+                .iter()
+                .any(|cond| &cond.name == condition)
+            {
+                let message = format!(
+                    "Condition {} does not exist in {} project",
+                    condition, &project.name
+                );
+                warn!("{}", message.yellow());
+                return Ok(None);
+            }
+            let usages = remote_config.condition_usages(condition);
+            return Ok(Some(Usages::Condition(usages)));
+        }
+        let name = self.name.as_deref().unwrap_or_default();
+        match remote_config.parameter_conditions(name) {
+            Some(conditions) => Ok(Some(Usages::Parameter(conditions))),
+            None => {
+                let message = format!("Parameter with name {} does not exists!", name);
+                warn!("{}", message.yellow());
+                Ok(None)
+            }
+        }
+    }
+
+    fn add_condition_rows(
+        table: &mut Table,
+        project_name: &str,
+        condition: &str,
+        usages: &[ConditionUsage],
+    ) {
+        let title = format!(
+            "{} parameter(s) use {} condition in {} project",
+            usages.len(),
+            condition,
+            project_name
+        );
+        table.add_row(Row::new(vec![TableCell::new_with_alignment(
+            title,
+            3,
+            Alignment::Center,
+        )]));
+        if usages.is_empty() {
+            return;
+        }
+        table.add_row(Row::new(vec![
+            TableCell::new("Parameter"),
+            TableCell::new("Group"),
+            TableCell::new("Value"),
+        ]));
+        for usage in usages {
+            table.add_row(Row::new(vec![
+                TableCell::new(&usage.parameter),
+                TableCell::new(usage.group.as_deref().unwrap_or_default()),
+                TableCell::new(&usage.value),
+            ]));
+        }
+    }
+
+    fn add_parameter_rows(
+        table: &mut Table,
+        project_name: &str,
+        name: &str,
+        conditions: &[ParameterCondition],
+    ) {
+        let title = format!(
+            "Conditions of {} parameter in {} project by priority",
+            name, project_name
+        );
+        table.add_row(Row::new(vec![TableCell::new_with_alignment(
+            title,
+            4,
+            Alignment::Center,
+        )]));
+        if conditions.is_empty() {
+            return;
+        }
+        table.add_row(Row::new(vec![
+            TableCell::new("Priority"),
+            TableCell::new("Condition"),
+            TableCell::new("Expression"),
+            TableCell::new("Value"),
+        ]));
+        for condition in conditions {
+            table.add_row(Row::new(vec![
+                TableCell::new(condition.priority),
+                TableCell::new(&condition.condition),
+                TableCell::new(&condition.expression),
+                TableCell::new(&condition.value),
+            ]));
+        }
+    }
+}
+
+#[async_trait]
+impl<NS: NetworkService + Send> Command for WhereUsedCommand<NS> {
+    async fn run_for_single_project(mut self, project: &Project) -> Result<()> {
+        self.run(std::slice::from_ref(project)).await
+    }
+
+    async fn run_for_multiple_projects(mut self, projects: &[Project]) -> Result<()> {
+        self.run(projects).await
+    }
+}
+
+impl RemoteConfig {
+    /// Root and grouped parameters with conditional value for the condition, ordered by name.
+    fn condition_usages(&self, condition: &str) -> Vec<ConditionUsage> {
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|(name, parameter)| (None, name, parameter))
+            .chain(
+                self.parameter_groups
+                    .iter()
+                    .flat_map(|(group_name, group)| {
+                        group
+                            .parameters
+                            .iter()
+                            .map(move |(name, parameter)| (Some(group_name), name, parameter))
+                    }),
+            );
+        let mut usages = parameters
+            .filter_map(|(group, name, parameter)| {
+                parameter
+                    .conditional_values
+                    .get(condition)
+                    .map(|value| ConditionUsage {
+                        parameter: name.clone(),
+                        group: group.cloned(),
+                        value: value.cell_content().to_string(),
+                    })
+            })
+            .collect::<Vec<_>>();
+        usages.sort_by(|lhs, rhs| lhs.parameter.cmp(&rhs.parameter));
+        usages
+    }
+
+    /// Conditional values of the parameter in the order conditions are evaluated,
+    /// returns `None` if the parameter does not exist.
+    fn parameter_conditions(&self, name: &str) -> Option<Vec<ParameterCondition>> {
+        let (_, parameter) = self.find_parameter(name)?;
+        let conditions = self
+            .conditions
+            .iter()
+            .filter_map(|condition| {
+                parameter
+                    .conditional_values
+                    .get(&condition.name)
+                    .map(|value| (condition, value))
+            })
+            .enumerate()
+            .map(|(index, (condition, value))| ParameterCondition {
+                priority: index + 1,
+                condition: condition.name.clone(),
+                expression: condition.expression.clone(),
+                value: value.cell_content().to_string(),
+            })
+            .collect();
+        Some(conditions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::remote_config::{Condition, Parameter, ParameterGroup, ParameterValue, TagColor};
+    use std::collections::HashMap;
+
+    #[test]
+    fn conditions_listed_by_priority() {
+        let mut config = RemoteConfig::default();
+        for name in ["Beta", "iOS", "Android"] {
+            config.conditions.push(Condition {
+                name: name.to_string(),
+                expression: format!("{} expression", name),
+                tag_color: TagColor::Blue,
+            });
+        }
+        let mut parameter = Parameter::stub();
+        parameter.conditional_values = HashMap::from([
+            ("iOS".to_string(), ParameterValue::Value("true".to_string())),
+            ("Beta".to_string(), ParameterValue::UseInAppDefault(true)),
+        ]);
+        config
+            .parameters
+            .insert("upload_logs".to_string(), parameter.clone());
+        let group = ParameterGroup {
+            description: None,
+            parameters: HashMap::from([("camera".to_string(), parameter)]),
+        };
+        config.parameter_groups.insert("Camera".to_string(), group);
+
+        let usages = config.condition_usages("iOS");
+        let conditions = config.parameter_conditions("camera").unwrap();
+
+        assert_eq!(
+            usages,
+            vec![
+                ConditionUsage {
+                    parameter: "camera".to_string(),
+                    group: Some("Camera".to_string()),
+                    value: "true".to_string(),
+                },
+                ConditionUsage {
+                    parameter: "upload_logs".to_string(),
+                    group: None,
+                    value: "true".to_string(),
+                },
+            ]
+        );
+        let names = conditions
+            .iter()
+            .map(|condition| (condition.priority, condition.condition.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec![(1, "Beta"), (2, "iOS")]);
+        assert!(config.condition_usages("Android").is_empty());
+        assert!(config.parameter_conditions("missing").is_none());
+    }
+}
@@ -1,10 +1,18 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
 use term_table::row::Row;
 use term_table::table_cell::TableCell;
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct Config {
     pub projects: Vec<Project>,
+    /// JSON Schemas of parameter values by parameter name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub schemas: HashMap<String, serde_json::Value>,
+    /// Directory with `<parameter name>.json` schemas, `schemas` in config directory by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_dir: Option<PathBuf>,
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
@@ -22,6 +30,10 @@ impl Project {
             app_ids,
         }
     }
+    pub fn project_number(&self) -> &str {
+        &self.project_number
+    }
+
     pub fn url(&self) -> String {
         format!(
             "https://firebaseremoteconfig.googleapis.com/v1/projects/{}/remoteConfig",
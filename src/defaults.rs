@@ -0,0 +1,449 @@
+use crate::cli::DefaultsFormat;
+use crate::error::{Error, Result};
+use crate::remote_config::{Parameter, ParameterValue, ParameterValueType, RemoteConfig};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::path::Path;
+use tracing::warn;
+
+/// In-app default value, typed the way Firebase SDKs accept it in `setDefaults`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DefaultValue {
+    Boolean(bool),
+    Number(String),
+    String(String),
+}
+
+impl DefaultValue {
+    /// Returns `None` for parameters without default value or using in-app default.
+    pub fn from_parameter(parameter: &Parameter) -> Option<Self> {
+        let value = match parameter.default_value.as_ref()? {
+            ParameterValue::Value(value) => value,
+            ParameterValue::UseInAppDefault(_) => return None,
+        };
+        let default_value = match parameter.value_type {
+            ParameterValueType::Boolean => match value.as_str() {
+                "true" => DefaultValue::Boolean(true),
+                "false" => DefaultValue::Boolean(false),
+                _ => DefaultValue::String(value.clone()),
+            },
+            ParameterValueType::Number if is_json_number(value) => {
+                DefaultValue::Number(value.clone())
+            }
+            _ => DefaultValue::String(value.clone()),
+        };
+        Some(default_value)
+    }
+
+    /// Infers value from untyped text, as Android defaults store it.
+    fn infer(text: String) -> Self {
+        match text.as_str() {
+            "true" => DefaultValue::Boolean(true),
+            "false" => DefaultValue::Boolean(false),
+            _ if is_json_number(&text) => DefaultValue::Number(text),
+            _ => DefaultValue::String(text),
+        }
+    }
+
+    pub fn value_type(&self) -> ParameterValueType {
+        match self {
+            DefaultValue::Boolean(_) => ParameterValueType::Boolean,
+            DefaultValue::Number(_) => ParameterValueType::Number,
+            DefaultValue::String(value) => match serde_json::from_str(value) {
+                Ok(serde_json::Value::Object(_) | serde_json::Value::Array(_)) => {
+                    ParameterValueType::Json
+                }
+                _ => ParameterValueType::String,
+            },
+        }
+    }
+
+    /// Compares values ignoring number formatting, e.g. `1` and `1.0` are equal.
+    pub fn is_equivalent(&self, other: &DefaultValue) -> bool {
+        match (self, other) {
+            (DefaultValue::Number(lhs), DefaultValue::Number(rhs)) => {
+                lhs.parse::<f64>().ok() == rhs.parse::<f64>().ok()
+            }
+            _ => self.as_str() == other.as_str(),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            DefaultValue::Boolean(true) => "true",
+            DefaultValue::Boolean(false) => "false",
+            DefaultValue::Number(value) | DefaultValue::String(value) => value,
+        }
+    }
+}
+
+/// Accepts only JSON number syntax, so `inf` or `NaN` stay strings.
+fn is_json_number(text: &str) -> bool {
+    text.parse::<serde_json::Number>().is_ok()
+}
+
+/// Default values of root and grouped parameters ordered by name
+/// and names of parameters using in-app default.
+pub fn collect_defaults(
+    remote_config: &RemoteConfig,
+) -> (BTreeMap<String, DefaultValue>, Vec<String>) {
+    let mut defaults = BTreeMap::new();
+    let mut in_app_defaults = Vec::new();
+    let parameters = remote_config.parameters.iter().chain(
+        remote_config
+            .parameter_groups
+            .values()
+            .flat_map(|group| group.parameters.iter()),
+    );
+    for (name, parameter) in parameters {
+        if let Some(ParameterValue::UseInAppDefault(_)) = parameter.default_value {
+            in_app_defaults.push(name.clone());
+        }
+        if let Some(value) = DefaultValue::from_parameter(parameter) {
+            defaults.insert(name.clone(), value);
+        }
+    }
+    in_app_defaults.sort();
+    (defaults, in_app_defaults)
+}
+
+pub fn render(format: DefaultsFormat, defaults: &BTreeMap<String, DefaultValue>) -> String {
+    match format {
+        DefaultsFormat::Plist => render_plist(defaults),
+        DefaultsFormat::AndroidXml => render_android_xml(defaults),
+        DefaultsFormat::Json => render_json(defaults),
+    }
+}
+
+/// Detects format by file extension, falling back to the content.
+pub fn detect_format(path: &Path, content: &str) -> Option<DefaultsFormat> {
+    let extension = path.extension().and_then(|extension| extension.to_str());
+    match extension {
+        Some("plist") => Some(DefaultsFormat::Plist),
+        Some("json") => Some(DefaultsFormat::Json),
+        _ if content.contains("<plist") => Some(DefaultsFormat::Plist),
+        _ if content.contains("<defaultsMap") => Some(DefaultsFormat::AndroidXml),
+        _ if content.trim_start().starts_with('{') => Some(DefaultsFormat::Json),
+        _ => None,
+    }
+}
+
+pub fn parse(format: DefaultsFormat, content: &str) -> Result<BTreeMap<String, DefaultValue>> {
+    match format {
+        DefaultsFormat::Plist => Ok(parse_plist(content)),
+        DefaultsFormat::AndroidXml => Ok(parse_android_xml(content)),
+        DefaultsFormat::Json => parse_json(content),
+    }
+}
+
+/// Reads keys of the top level dictionary, nested dictionaries, arrays, dates and data are skipped.
+fn parse_plist(content: &str) -> BTreeMap<String, DefaultValue> {
+    let regex = Regex::new(r"<(/?)([A-Za-z]+)[^<>]*?(/?)>").unwrap();
+    let tags = regex.captures_iter(content).collect::<Vec<_>>();
+    let text = |index: usize| {
+        let start = tags[index].get(0).unwrap().end();
+        let end = tags
+            .get(index + 1)
+            .map(|tag| tag.get(0).unwrap().start())
+            .unwrap_or(content.len());
+        unescape_xml(&content[start..end])
+    };
+    let mut defaults = BTreeMap::new();
+    let mut depth = 0;
+    let mut dict_depth = None;
+    let mut key = None;
+    for (index, tag) in tags.iter().enumerate() {
+        let is_closing = !tag[1].is_empty();
+        let is_empty = !tag[3].is_empty();
+        let name = &tag[2];
+        if is_closing {
+            depth -= 1;
+            continue;
+        }
+        let is_top_level = dict_depth == Some(depth);
+        if !is_empty {
+            depth += 1;
+        }
+        if dict_depth.is_none() {
+            if name == "dict" {
+                dict_depth = Some(depth);
+            }
+            continue;
+        }
+        if !is_top_level {
+            continue;
+        }
+        if name == "key" {
+            key = Some(text(index));
+            continue;
+        }
+        let key = match key.take() {
+            Some(key) => key,
+            None => continue,
+        };
+        let value = match (name, is_empty) {
+            ("true", true) => DefaultValue::Boolean(true),
+            ("false", true) => DefaultValue::Boolean(false),
+            ("integer" | "real", false) => DefaultValue::Number(text(index).trim().to_string()),
+            ("string", false) => DefaultValue::String(text(index)),
+            ("string", true) => DefaultValue::String(String::new()),
+            _ => {
+                warn!(
+                    "Skipping {} default value, {} values are not supported",
+                    key, name
+                );
+                continue;
+            }
+        };
+        defaults.insert(key, value);
+    }
+    defaults
+}
+
+fn parse_android_xml(content: &str) -> BTreeMap<String, DefaultValue> {
+    let regex = Regex::new(
+        r"(?s)<entry>\s*<key>([^<]*)</key>\s*(?:<value>(.*?)</value>|<value\s*/>)\s*</entry>",
+    )
+    .unwrap();
+    regex
+        .captures_iter(content)
+        .map(|captures| {
+            let value = captures
+                .get(2)
+                .map(|value| unescape_xml(value.as_str()))
+                .unwrap_or_default();
+            (unescape_xml(&captures[1]), DefaultValue::infer(value))
+        })
+        .collect()
+}
+
+fn parse_json(content: &str) -> Result<BTreeMap<String, DefaultValue>> {
+    let map = serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(content)
+        .map_err(|_| Error::new("Defaults JSON must be an object with parameter values."))?;
+    let defaults = map
+        .into_iter()
+        .map(|(name, value)| {
+            let value = match value {
+                serde_json::Value::Bool(value) => DefaultValue::Boolean(value),
+                serde_json::Value::Number(value) => DefaultValue::Number(value.to_string()),
+                serde_json::Value::String(value) => DefaultValue::String(value),
+                serde_json::Value::Null => DefaultValue::String(String::new()),
+                value => DefaultValue::String(value.to_string()),
+            };
+            (name, value)
+        })
+        .collect();
+    Ok(defaults)
+}
+
+fn render_plist(defaults: &BTreeMap<String, DefaultValue>) -> String {
+    let mut lines = vec![
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string(),
+        r#"<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">"#.to_string(),
+        r#"<plist version="1.0">"#.to_string(),
+        "<dict>".to_string(),
+    ];
+    for (name, value) in defaults {
+        lines.push(format!("\t<key>{}</key>", escape_xml(name)));
+        let value = match value {
+            DefaultValue::Boolean(value) => format!("\t<{}/>", value),
+            DefaultValue::Number(value) if value.parse::<i64>().is_ok() => {
+                format!("\t<integer>{}</integer>", value)
+            }
+            DefaultValue::Number(value) => format!("\t<real>{}</real>", value),
+            DefaultValue::String(value) => format!("\t<string>{}</string>", escape_xml(value)),
+        };
+        lines.push(value);
+    }
+    lines.push("</dict>".to_string());
+    lines.push("</plist>".to_string());
+    lines.join("\n") + "\n"
+}
+
+fn render_android_xml(defaults: &BTreeMap<String, DefaultValue>) -> String {
+    let mut lines = vec![
+        r#"<?xml version="1.0" encoding="utf-8"?>"#.to_string(),
+        "<defaultsMap>".to_string(),
+    ];
+    for (name, value) in defaults {
+        lines.push("    <entry>".to_string());
+        lines.push(format!("        <key>{}</key>", escape_xml(name)));
+        lines.push(format!(
+            "        <value>{}</value>",
+            escape_xml(value.as_str())
+        ));
+        lines.push("    </entry>".to_string());
+    }
+    lines.push("</defaultsMap>".to_string());
+    lines.join("\n") + "\n"
+}
+
+fn render_json(defaults: &BTreeMap<String, DefaultValue>) -> String {
+    let map = defaults
+        .iter()
+        .map(|(name, value)| {
+            let value = match value {
+                DefaultValue::Boolean(value) => serde_json::Value::Bool(*value),
+                DefaultValue::Number(value) => serde_json::from_str(value)
+                    .unwrap_or_else(|_| serde_json::Value::String(value.clone())),
+                DefaultValue::String(value) => serde_json::Value::String(value.clone()),
+            };
+            (name.clone(), value)
+        })
+        .collect::<serde_json::Map<_, _>>();
+    serde_json::to_string_pretty(&map).unwrap() + "\n"
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_app_defaults_are_skipped() {
+        let mut config = RemoteConfig::default();
+        let mut parameter = Parameter::stub();
+        parameter.value_type = ParameterValueType::Boolean;
+        parameter.default_value = Some(ParameterValue::Value("true".to_string()));
+        config
+            .parameters
+            .insert("upload_logs".to_string(), parameter);
+        let mut parameter = Parameter::stub();
+        parameter.value_type = ParameterValueType::Number;
+        parameter.default_value = Some(ParameterValue::Value("1.5".to_string()));
+        config.parameters.insert("ratio".to_string(), parameter);
+        let mut parameter = Parameter::stub();
+        parameter.default_value = Some(ParameterValue::UseInAppDefault(true));
+        config.parameters.insert("title".to_string(), parameter);
+
+        let (defaults, in_app_defaults) = collect_defaults(&config);
+
+        assert_eq!(in_app_defaults, vec!["title".to_string()]);
+        assert_eq!(
+            render(DefaultsFormat::Plist, &defaults),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+	<key>ratio</key>
+	<real>1.5</real>
+	<key>upload_logs</key>
+	<true/>
+</dict>
+</plist>
+"#
+        );
+        assert_eq!(
+            render(DefaultsFormat::Json, &defaults),
+            "{\n  \"ratio\": 1.5,\n  \"upload_logs\": true\n}\n"
+        );
+    }
+
+    #[test]
+    fn parsed_defaults_infer_value_type() {
+        let content = r#"<?xml version="1.0" encoding="utf-8"?>
+<defaultsMap>
+    <entry>
+        <key>upload_logs</key>
+        <value>true</value>
+    </entry>
+    <entry>
+        <key>ratio</key>
+        <value>1.5</value>
+    </entry>
+    <entry>
+        <key>payload</key>
+        <value>{&quot;size&quot;: 1}</value>
+    </entry>
+</defaultsMap>
+"#;
+        let format = detect_format(Path::new("defaults.xml"), content);
+        assert_eq!(format, Some(DefaultsFormat::AndroidXml));
+
+        let defaults = parse(format.unwrap(), content).unwrap();
+
+        let value_types = defaults
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.value_type()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            value_types,
+            vec![
+                ("payload", ParameterValueType::Json),
+                ("ratio", ParameterValueType::Number),
+                ("upload_logs", ParameterValueType::Boolean),
+            ]
+        );
+        let rendered = render(DefaultsFormat::Plist, &defaults);
+        assert_eq!(parse(DefaultsFormat::Plist, &rendered).unwrap(), defaults);
+    }
+
+    #[test]
+    fn only_json_numbers_are_inferred_as_numbers() {
+        let value_types = ["1", "-2.5e3", "inf", "NaN", "infinity", "1_000"]
+            .map(|text| DefaultValue::infer(text.to_string()).value_type());
+        assert_eq!(
+            value_types,
+            [
+                ParameterValueType::Number,
+                ParameterValueType::Number,
+                ParameterValueType::String,
+                ParameterValueType::String,
+                ParameterValueType::String,
+                ParameterValueType::String,
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_plist_values_are_skipped() {
+        let content = r#"<plist version="1.0">
+<dict>
+	<key>colors</key>
+	<array><string>red</string></array>
+	<key>flag</key>
+	<true/>
+	<key>settings</key>
+	<dict><key>inner</key><false/></dict>
+	<key>created</key>
+	<date>2022-05-01T00:00:00Z</date>
+	<key>title</key>
+	<string>Hello &amp; welcome</string>
+	<key>empty</key>
+	<string/>
+</dict>
+</plist>
+"#;
+
+        let defaults = parse(DefaultsFormat::Plist, content).unwrap();
+
+        assert_eq!(
+            defaults,
+            BTreeMap::from([
+                ("empty".to_string(), DefaultValue::String(String::new())),
+                ("flag".to_string(), DefaultValue::Boolean(true)),
+                (
+                    "title".to_string(),
+                    DefaultValue::String("Hello & welcome".to_string())
+                ),
+            ])
+        );
+    }
+}
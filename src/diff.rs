@@ -0,0 +1,355 @@
+use crate::remote_config::{Condition, Parameter, ParameterGroup, RemoteConfig};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Single change between two versions of remote config.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Change {
+    Parameter {
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        before: Option<GroupedParameter>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        after: Option<GroupedParameter>,
+    },
+    Condition {
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        before: Option<Condition>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        after: Option<Condition>,
+    },
+}
+
+/// Parameter with the name of the group it belongs to.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GroupedParameter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    #[serde(flatten)]
+    pub parameter: Parameter,
+}
+
+impl Change {
+    pub fn name(&self) -> &str {
+        match self {
+            Change::Parameter { name, .. } | Change::Condition { name, .. } => name,
+        }
+    }
+
+    pub fn is_parameter(&self) -> bool {
+        matches!(self, Change::Parameter { .. })
+    }
+
+    /// Short description of the change, e.g. `updated parameter upload_logs`.
+    pub fn summary(&self) -> String {
+        let (kind, is_added, is_deleted) = match self {
+            Change::Parameter { before, after, .. } => {
+                ("parameter", before.is_none(), after.is_none())
+            }
+            Change::Condition { before, after, .. } => {
+                ("condition", before.is_none(), after.is_none())
+            }
+        };
+        let action = match (is_added, is_deleted) {
+            (true, _) => "added",
+            (_, true) => "deleted",
+            _ => "updated",
+        };
+        format!("{} {} {}", action, kind, self.name())
+    }
+}
+
+impl RemoteConfig {
+    /// Returns changes which turn `self` into `other`, parameters first, ordered by name.
+    pub fn diff(&self, other: &RemoteConfig) -> Vec<Change> {
+        let before = self.grouped_parameters();
+        let after = other.grouped_parameters();
+        let mut names = before.keys().chain(after.keys()).collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+        let mut changes = names
+            .into_iter()
+            .filter(|name| before.get(*name) != after.get(*name))
+            .map(|name| Change::Parameter {
+                name: name.to_string(),
+                before: before.get(name).cloned(),
+                after: after.get(name).cloned(),
+            })
+            .collect::<Vec<_>>();
+
+        let before = self.conditions_by_name();
+        let after = other.conditions_by_name();
+        let mut names = before.keys().chain(after.keys()).collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+        names
+            .into_iter()
+            .filter(|name| {
+                let before = before.get(*name).copied();
+                let after = after.get(*name).copied();
+                match (before, after) {
+                    (Some(before), Some(after)) => !before.is_identical(after),
+                    _ => true,
+                }
+            })
+            .map(|name| Change::Condition {
+                name: name.to_string(),
+                before: before.get(name).copied().cloned(),
+                after: after.get(name).copied().cloned(),
+            })
+            .for_each(|change| changes.push(change));
+        changes
+    }
+
+    /// Reverts changes which turned `before` into `after`.
+    /// Parameters and conditions changed since `after` are left untouched and returned as conflicts.
+    pub fn revert(&mut self, before: &RemoteConfig, after: &RemoteConfig) -> Vec<Change> {
+        let mut conflicts = Vec::new();
+        for change in before.diff(after) {
+            match &change {
+                Change::Parameter {
+                    name,
+                    before: previous,
+                    after: published,
+                } => {
+                    let current = self.grouped_parameter(name);
+                    if &current == published {
+                        self.set_grouped_parameter(name, previous.clone(), before);
+                    } else if &current != previous {
+                        conflicts.push(change);
+                    }
+                }
+                Change::Condition {
+                    name,
+                    before: previous,
+                    after: published,
+                } => {
+                    let current = self
+                        .conditions
+                        .iter()
+                        .find(|condition| &condition.name == name);
+                    let is_identical =
+                        |lhs: Option<&Condition>, rhs: &Option<Condition>| match (lhs, rhs) {
+                            (Some(lhs), Some(rhs)) => lhs.is_identical(rhs),
+                            (None, None) => true,
+                            _ => false,
+                        };
+                    let is_used = self
+                        .parameters
+                        .values()
+                        .chain(
+                            self.parameter_groups
+                                .values()
+                                .flat_map(|group| group.parameters.values()),
+                        )
+                        .any(|parameter| parameter.conditional_values.contains_key(name));
+                    if previous.is_none() && is_used {
+                        conflicts.push(change);
+                    } else if is_identical(current, published) {
+                        self.set_condition(name, previous.clone(), before);
+                    } else if !is_identical(current, previous) {
+                        conflicts.push(change);
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+
+    pub fn grouped_parameter(&self, name: &str) -> Option<GroupedParameter> {
+        self.find_parameter(name)
+            .map(|(group, parameter)| GroupedParameter {
+                group: group.map(String::from),
+                parameter: parameter.clone(),
+            })
+    }
+
+    /// Replaces or removes parameter, group description is taken from `reference` for new groups.
+    fn set_grouped_parameter(
+        &mut self,
+        name: &str,
+        value: Option<GroupedParameter>,
+        reference: &RemoteConfig,
+    ) {
+        self.parameters.remove(name);
+        for group in self.parameter_groups.values_mut() {
+            group.parameters.remove(name);
+        }
+        self.parameter_groups.retain(|group_name, group| {
+            !group.parameters.is_empty() || reference.parameter_groups.contains_key(group_name)
+        });
+        let value = match value {
+            None => return,
+            Some(value) => value,
+        };
+        match value.group {
+            None => {
+                self.parameters.insert(name.to_string(), value.parameter);
+            }
+            Some(group_name) => {
+                let description = reference
+                    .parameter_groups
+                    .get(&group_name)
+                    .and_then(|group| group.description.clone());
+                self.parameter_groups
+                    .entry(group_name)
+                    .or_insert_with(|| ParameterGroup {
+                        description,
+                        parameters: HashMap::new(),
+                    })
+                    .parameters
+                    .insert(name.to_string(), value.parameter);
+            }
+        }
+    }
+
+    /// Replaces or removes condition, new condition is inserted at its position in `reference`.
+    fn set_condition(&mut self, name: &str, value: Option<Condition>, reference: &RemoteConfig) {
+        let index = self
+            .conditions
+            .iter()
+            .position(|condition| condition.name == name);
+        match (index, value) {
+            (Some(index), Some(value)) => self.conditions[index] = value,
+            (Some(index), None) => {
+                self.conditions.remove(index);
+            }
+            (None, Some(value)) => {
+                let index = reference
+                    .conditions
+                    .iter()
+                    .position(|condition| condition.name == name)
+                    .unwrap_or(self.conditions.len())
+                    .min(self.conditions.len());
+                self.conditions.insert(index, value);
+            }
+            (None, None) => {}
+        }
+    }
+
+    fn grouped_parameters(&self) -> BTreeMap<&str, GroupedParameter> {
+        let root = self.parameters.iter().map(|(name, parameter)| {
+            let parameter = GroupedParameter {
+                group: None,
+                parameter: parameter.clone(),
+            };
+            (name.as_str(), parameter)
+        });
+        let grouped = self
+            .parameter_groups
+            .iter()
+            .flat_map(|(group_name, group)| {
+                group.parameters.iter().map(|(name, parameter)| {
+                    let parameter = GroupedParameter {
+                        group: Some(group_name.clone()),
+                        parameter: parameter.clone(),
+                    };
+                    (name.as_str(), parameter)
+                })
+            });
+        root.chain(grouped).collect()
+    }
+
+    fn conditions_by_name(&self) -> BTreeMap<&str, &Condition> {
+        self.conditions
+            .iter()
+            .map(|condition| (condition.name.as_str(), condition))
+            .collect()
+    }
+}
+
+impl Condition {
+    /// Unlike `==`, which compares only names, checks that all fields are equal.
+    pub fn is_identical(&self, other: &Condition) -> bool {
+        self.name == other.name
+            && self.expression == other.expression
+            && self.tag_color == other.tag_color
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::remote_config::{ParameterValue, TagColor};
+
+    #[test]
+    fn diff_contains_moved_parameter_and_changed_condition() {
+        let mut before = RemoteConfig::default();
+        before
+            .parameters
+            .insert("upload_logs".to_string(), Parameter::stub());
+        before
+            .parameters
+            .insert("unchanged".to_string(), Parameter::stub());
+        before.conditions.push(Condition {
+            name: "iOS".to_string(),
+            expression: "device.os == 'ios'".to_string(),
+            tag_color: TagColor::Blue,
+        });
+        let mut after = before.clone();
+        let mut parameter = after.parameters.remove("upload_logs").unwrap();
+        parameter.default_value = Some(ParameterValue::Value("true".to_string()));
+        let group = ParameterGroup {
+            description: None,
+            parameters: HashMap::from([("upload_logs".to_string(), parameter)]),
+        };
+        after.parameter_groups.insert("Logs".to_string(), group);
+        after.conditions[0].tag_color = TagColor::Green;
+
+        let changes = before.diff(&after);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].summary(), "updated parameter upload_logs");
+        match &changes[0] {
+            Change::Parameter { after, .. } => {
+                assert_eq!(after.as_ref().unwrap().group.as_deref(), Some("Logs"))
+            }
+            _ => panic!("Parameter change expected"),
+        }
+        assert_eq!(changes[1].summary(), "updated condition iOS");
+        assert!(after.diff(&after).is_empty());
+    }
+
+    #[test]
+    fn revert_keeps_remote_changes() {
+        let mut before = RemoteConfig::default();
+        before
+            .parameters
+            .insert("upload_logs".to_string(), Parameter::stub());
+        before
+            .parameters
+            .insert("camera".to_string(), Parameter::stub());
+        let mut after = before.clone();
+        let value = Some(ParameterValue::Value("true".to_string()));
+        after
+            .parameters
+            .get_mut("upload_logs")
+            .unwrap()
+            .default_value = value.clone();
+        after.parameters.get_mut("camera").unwrap().default_value = value.clone();
+        after
+            .parameters
+            .insert("new_flag".to_string(), Parameter::stub());
+
+        let mut current = after.clone();
+        let mut remote_parameter = Parameter::stub();
+        remote_parameter.description = Some("Changed remotely".to_string());
+        current
+            .parameters
+            .insert("camera".to_string(), remote_parameter.clone());
+        current
+            .parameters
+            .insert("remote_flag".to_string(), Parameter::stub());
+
+        let conflicts = current.revert(&before, &after);
+
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].name(), "camera");
+        assert_eq!(current.parameters["upload_logs"], Parameter::stub());
+        assert_eq!(current.parameters["camera"], remote_parameter);
+        assert!(!current.parameters.contains_key("new_flag"));
+        assert!(current.parameters.contains_key("remote_flag"));
+    }
+}
@@ -7,11 +7,21 @@ use tracing::warn;
 
 pub struct InputReader<E: Editor> {
     editor: E,
+    is_auto_confirmed: bool,
 }
 
 impl<E: Editor> InputReader<E> {
     pub fn new(editor: E) -> Self {
-        Self { editor }
+        Self {
+            editor,
+            is_auto_confirmed: false,
+        }
+    }
+
+    /// Makes every confirmation request succeed without reading user input
+    pub fn with_auto_confirmation(mut self, is_auto_confirmed: bool) -> Self {
+        self.is_auto_confirmed = is_auto_confirmed;
+        self
     }
 
     pub fn request_user_input<M>(&mut self, request_msg: &M) -> Result<String>
@@ -26,6 +36,11 @@ impl<E: Editor> InputReader<E> {
     where
         M: Display + ?Sized,
     {
+        if self.is_auto_confirmed {
+            println!("  {}", confirmation_msg);
+            println!("> y");
+            return true;
+        }
         loop {
             let result = self
                 .request_user_input::<M>(confirmation_msg)
@@ -75,3 +90,38 @@ pub fn request_select_item_in_list<'a>(
         Some(selected_index - 2)
     }
 }
+
+/// Shows the list where every item can be checked or unchecked.
+/// Returns indices of checked items or None if user went back.
+pub fn request_select_items_in_list(
+    label: &str,
+    list: &[&str],
+    initially_selected: bool,
+) -> Option<Vec<usize>> {
+    let mut selected = vec![initially_selected; list.len()];
+    loop {
+        let items = list
+            .iter()
+            .zip(selected.iter())
+            .map(|(item, is_selected)| {
+                let mark = if *is_selected { "[x]" } else { "[ ]" };
+                format!("{} {}", mark, item)
+            })
+            .collect::<Vec<_>>();
+        let index = request_select_item_in_list(
+            label,
+            items.iter().map(|item| item.as_str()),
+            Some("Done"),
+        )?;
+        if index == list.len() {
+            let indices = selected
+                .iter()
+                .enumerate()
+                .filter(|(_, is_selected)| **is_selected)
+                .map(|(index, _)| index)
+                .collect();
+            return Some(indices);
+        }
+        selected[index] = !selected[index];
+    }
+}
@@ -0,0 +1,118 @@
+use crate::diff::Change;
+use crate::error::{Error, Result};
+use chrono::{DateTime, Utc};
+use directories_next::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::ErrorKind::NotFound;
+use std::io::Write;
+use std::path::PathBuf;
+use tracing::{debug, warn};
+
+const JOURNAL_FILE_NAME: &str = "journal.jsonl";
+
+/// Append-only log of published changes stored in the data directory, one JSON entry per line.
+pub struct Journal {
+    app_name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JournalEntry {
+    pub timestamp: DateTime<Utc>,
+    pub user: String,
+    pub project: String,
+    pub project_number: String,
+    pub command: String,
+    pub previous_etag: String,
+    pub etag: Option<String>,
+    pub changes: Vec<Change>,
+}
+
+impl JournalEntry {
+    /// Creates entry for the current user and command line.
+    pub fn new(
+        project: String,
+        project_number: String,
+        previous_etag: String,
+        etag: Option<String>,
+        changes: Vec<Change>,
+    ) -> Self {
+        let user = std::env::var("USER")
+            .or_else(|_| std::env::var("USERNAME"))
+            .unwrap_or_else(|_| "unknown".to_string());
+        Self {
+            timestamp: Utc::now(),
+            user,
+            project,
+            project_number,
+            command: std::env::args().collect::<Vec<_>>().join(" "),
+            previous_etag,
+            etag,
+            changes,
+        }
+    }
+}
+
+impl Journal {
+    pub fn new(app_name: String) -> Self {
+        Self { app_name }
+    }
+
+    pub fn append(&self, entry: &JournalEntry) -> Result<()> {
+        let path = self.journal_file_path()?;
+        let parent_path = path.parent().ok_or_else(|| Error::new("Invalid path."))?;
+        fs::create_dir_all(parent_path).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error::new("Failed to create data directory.")
+        })?;
+        let mut line = serde_json::to_string(entry).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error::new("Failed to serialize journal entry.")
+        })?;
+        line.push('\n');
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|error| {
+                debug!("Error: {:?}", error);
+                Error::new("Failed to open journal file.")
+            })?;
+        file.write_all(line.as_bytes()).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error::new("Failed to write journal file.")
+        })
+    }
+
+    /// Loads all entries in order they were written. Malformed lines are skipped.
+    pub fn load(&self) -> Result<Vec<JournalEntry>> {
+        let path = self.journal_file_path()?;
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == NotFound => return Ok(Vec::new()),
+            Err(error) => {
+                debug!("Error: {:?}", error);
+                return Err(Error::new("Failed to read journal file."));
+            }
+        };
+        let entries = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .filter_map(|(index, line)| match serde_json::from_str(line) {
+                Ok(entry) => Some(entry),
+                Err(error) => {
+                    warn!("Skipping malformed journal line {}: {}", index + 1, error);
+                    None
+                }
+            })
+            .collect();
+        Ok(entries)
+    }
+
+    fn journal_file_path(&self) -> Result<PathBuf> {
+        let directories = ProjectDirs::from("com", "", &self.app_name)
+            .ok_or_else(|| Error::new("Could not determine project directories path"))?;
+        Ok(directories.data_dir().join(JOURNAL_FILE_NAME))
+    }
+}
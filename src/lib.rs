@@ -3,8 +3,14 @@ extern crate core;
 pub mod cli;
 pub mod commands;
 mod config;
+mod defaults;
+mod diff;
 mod editor;
 mod error;
 pub mod io;
+mod journal;
 pub mod network;
+mod pattern;
 mod remote_config;
+pub mod schema;
+mod snapshot;
@@ -2,42 +2,62 @@ use clap::Parser;
 use color_eyre::{owo_colors::OwoColorize, Report};
 use rcm::cli::{Cli, Command};
 use rcm::commands::{
-    AddCommand, CommandRunner, ConfigCommand, ConfigFile, DeleteCommand, MigrateCommand,
-    MoveOutCommand, MoveToCommand, ShowCommand, UpdateCommand,
+    AddCommand, BackupCommand, BatchCommand, CodegenCommand, CommandRunner, ConfigCommand,
+    ConfigFile, DefaultsCommand, DeleteCommand, EditCommand, FlagCommand, GcCommand, GroupCommand,
+    ImportDefaultsCommand, LintCommand, LogCommand, MigrateCommand, MoveOutCommand, MoveToCommand,
+    RestoreCommand, RolloutCommand, ScheduleCommand, ShowCommand, StatsCommand, UndoCommand,
+    UpdateCommand, WatchCommand, WhereUsedCommand,
 };
 use rcm::io::InputReader;
 use rcm::network::NetworkWorker;
+use rcm::schema::Schemas;
 use rustyline::Editor;
 use std::ffi::OsStr;
 use std::path::Path;
 use tracing::error;
 use tracing_subscriber::filter::EnvFilter;
 use tracing_subscriber::fmt;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
 
 #[tokio::main]
 async fn main() -> Result<(), Report> {
-    setup()?;
     let cli = Cli::parse();
+    setup(cli.is_structured_output())?;
     let app_name = app_name();
     let command_runner = CommandRunner::new(app_name.clone());
     let network_worker = NetworkWorker::new(app_name.clone());
     let input_reader = InputReader::new(Editor::new());
     let result = match cli.command {
         Command::Add(arguments) => {
+            let input_reader = input_reader.with_auto_confirmation(arguments.values.yes);
             let command = AddCommand::new(
                 arguments.name,
                 arguments.description,
+                arguments.values,
+                Schemas::load(&app_name)?,
                 network_worker,
                 input_reader,
             );
             command_runner.run(command, arguments.project).await
         }
         Command::Update(arguments) => {
-            let command = UpdateCommand::new(arguments.name, network_worker, input_reader);
+            let input_reader = input_reader.with_auto_confirmation(arguments.values.yes);
+            let command = UpdateCommand::new(
+                arguments.name,
+                arguments.values,
+                Schemas::load(&app_name)?,
+                network_worker,
+                input_reader,
+            );
             command_runner.run(command, arguments.project).await
         }
         Command::Delete(arguments) => {
-            let command = DeleteCommand::new(arguments.name, network_worker, input_reader);
+            let command = DeleteCommand::new(
+                arguments.name,
+                arguments.group,
+                network_worker,
+                input_reader,
+            );
             command_runner.run(command, arguments.project).await
         }
         Command::MoveTo(arguments) => {
@@ -54,11 +74,16 @@ async fn main() -> Result<(), Report> {
             command_runner.run(command, arguments.project).await
         }
         Command::Show(arguments) => {
-            command_runner
-                .run(ShowCommand::new(network_worker), arguments)
-                .await
+            let command = ShowCommand::new(
+                arguments.filter,
+                arguments.matrix,
+                arguments.output,
+                network_worker,
+            );
+            command_runner.run(command, arguments.project).await
         }
         Command::Config(arguments) => ConfigCommand::new(app_name, arguments).run(),
+        Command::Log(arguments) => LogCommand::new(arguments, app_name).run(),
         Command::Migrate(arguments) => {
             let config_file = ConfigFile::new(app_name);
             let projects = config_file.load()?.projects;
@@ -67,18 +92,130 @@ async fn main() -> Result<(), Report> {
                     arguments.source,
                     arg_projects,
                     &projects,
+                    arguments.options,
                     network_worker,
                     input_reader,
                 ),
                 None => MigrateCommand::new_for_all_projects(
                     arguments.source,
                     &projects,
+                    arguments.options,
                     network_worker,
                     input_reader,
                 ),
             }?;
             command.run().await
         }
+        Command::Group(mut subcommand) => {
+            let project = subcommand.take_project();
+            let command = GroupCommand::new(subcommand, network_worker, input_reader);
+            command_runner.run(command, project).await
+        }
+        Command::Batch(arguments) => {
+            let input_reader = input_reader.with_auto_confirmation(arguments.yes);
+            let command = BatchCommand::new(
+                arguments.path,
+                Schemas::load(&app_name)?,
+                network_worker,
+                input_reader,
+            );
+            command_runner.run(command, arguments.project).await
+        }
+        Command::Gc(arguments) => {
+            let command = GcCommand::new(arguments.yes, network_worker, input_reader);
+            command_runner.run(command, arguments.project).await
+        }
+        Command::Rollout(mut arguments) => {
+            let project = arguments.take_project();
+            let input_reader = input_reader.with_auto_confirmation(arguments.is_auto_confirmed());
+            let command = RolloutCommand::new(arguments, network_worker, input_reader);
+            command_runner.run(command, project).await
+        }
+        Command::Schedule(mut arguments) => {
+            let project = arguments.take_project();
+            let input_reader = input_reader.with_auto_confirmation(arguments.yes);
+            let command = ScheduleCommand::new(arguments, network_worker, input_reader);
+            command_runner.run(command, project).await
+        }
+        Command::Undo(arguments) => {
+            let input_reader = input_reader.with_auto_confirmation(arguments.yes);
+            let command = UndoCommand::new(arguments.steps, app_name, network_worker, input_reader);
+            command_runner.run(command, arguments.project).await
+        }
+        Command::Backup(arguments) => {
+            let command = BackupCommand::new(
+                arguments.out,
+                arguments.keep,
+                arguments.keep_days,
+                network_worker,
+            );
+            command_runner.run(command, arguments.project).await
+        }
+        Command::Restore(arguments) => {
+            let input_reader = input_reader.with_auto_confirmation(arguments.yes);
+            let command = RestoreCommand::new(arguments.from, network_worker, input_reader);
+            command_runner.run(command, arguments.project).await
+        }
+        Command::Watch(arguments) => {
+            let command = WatchCommand::new(arguments.interval, arguments.exec, network_worker);
+            command_runner.run(command, arguments.project).await
+        }
+        Command::Codegen(arguments) => {
+            let command = CodegenCommand::new(arguments.lang, arguments.out, network_worker);
+            command_runner.run(command, arguments.project).await
+        }
+        Command::Defaults(arguments) => {
+            let command = DefaultsCommand::new(arguments.format, arguments.out, network_worker);
+            match arguments.template {
+                Some(path) => command.run_for_template(&path),
+                None => command_runner.run(command, arguments.project).await,
+            }
+        }
+        Command::ImportDefaults(arguments) => {
+            let command = ImportDefaultsCommand::new(
+                arguments.file,
+                arguments.group,
+                arguments.yes,
+                Schemas::load(&app_name)?,
+                network_worker,
+                input_reader,
+            );
+            command_runner.run(command, arguments.project).await
+        }
+        Command::Lint(project) => {
+            let command = LintCommand::new(Schemas::load(&app_name)?, network_worker);
+            command_runner.run(command, project).await
+        }
+        Command::Edit(arguments) => {
+            let command = EditCommand::new(
+                arguments.name,
+                arguments.format,
+                Schemas::load(&app_name)?,
+                network_worker,
+                input_reader,
+            );
+            command_runner.run(command, arguments.project).await
+        }
+        Command::Stats(project) => {
+            let command = StatsCommand::new(network_worker);
+            command_runner.run(command, project).await
+        }
+        Command::WhereUsed(arguments) => {
+            let command =
+                WhereUsedCommand::new(arguments.condition, arguments.name, network_worker);
+            command_runner.run(command, arguments.project).await
+        }
+        Command::Flag(arguments) => {
+            let input_reader = input_reader.with_auto_confirmation(arguments.yes);
+            let command = FlagCommand::new(
+                arguments.action,
+                arguments.name,
+                arguments.condition,
+                network_worker,
+                input_reader,
+            );
+            command_runner.run(command, arguments.project).await
+        }
     };
     if let Err(error) = result {
         error!("{}", error.message.red())
@@ -86,7 +223,7 @@ async fn main() -> Result<(), Report> {
     Ok(())
 }
 
-fn setup() -> Result<(), Report> {
+fn setup(is_structured_output: bool) -> Result<(), Report> {
     if std::env::var("RUST_LIB_BACKTRACE").is_err() {
         std::env::set_var("RUST_LIB_BACKTRACE", "1")
     }
@@ -102,9 +239,15 @@ fn setup() -> Result<(), Report> {
         .with_timer(fmt::time::SystemTime::default())
         .compact();
 
+    let writer = if is_structured_output {
+        BoxMakeWriter::new(std::io::stderr)
+    } else {
+        BoxMakeWriter::new(std::io::stdout)
+    };
     fmt::fmt()
         .event_format(format)
         .with_env_filter(EnvFilter::from_default_env())
+        .with_writer(writer)
         .init();
 
     Ok(())
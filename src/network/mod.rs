@@ -1,17 +1,21 @@
 use crate::config::Project;
+use crate::journal::{Journal, JournalEntry};
 use crate::remote_config::RemoteConfig;
+use crate::snapshot::{Snapshot, SnapshotStore};
 use async_trait::async_trait;
 use authenticator::Authenticator;
+use chrono::Utc;
 #[cfg(test)]
 use mockall::automock;
 use reqwest::{
     header::{ACCEPT_ENCODING, AUTHORIZATION, ETAG, IF_MATCH},
     Client, ClientBuilder,
 };
-use spinners::{Spinner, Spinners};
+use spinners::{Spinner, Spinners, Stream};
+use std::collections::HashMap;
 use std::error::Error;
 use std::future::Future;
-use tracing::debug;
+use tracing::{debug, warn};
 
 mod authenticator;
 #[cfg_attr(test, automock)]
@@ -28,11 +32,23 @@ pub trait NetworkService {
         config: RemoteConfig,
         etag: String,
     ) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// Publishes config which reverts earlier changes, no snapshot is saved for it.
+    async fn revert_remote_config(
+        &mut self,
+        project: &Project,
+        config: RemoteConfig,
+        etag: String,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
 }
 
 pub struct NetworkWorker {
     client: Client,
     authenticator: Authenticator,
+    journal: Journal,
+    snapshot_store: SnapshotStore,
+    /// Latest known ETag and config by project number, used to record changes to the journal
+    fetched_configs: HashMap<String, (String, RemoteConfig)>,
 }
 
 pub struct ResponseWithEtag<T> {
@@ -44,7 +60,10 @@ impl NetworkWorker {
     pub fn new(app_name: String) -> Self {
         Self {
             client: ClientBuilder::new().gzip(true).build().unwrap(),
-            authenticator: Authenticator::new(app_name),
+            authenticator: Authenticator::new(app_name.clone()),
+            journal: Journal::new(app_name.clone()),
+            snapshot_store: SnapshotStore::new(app_name),
+            fetched_configs: HashMap::new(),
         }
     }
 
@@ -56,14 +75,15 @@ impl NetworkWorker {
     where
         F: Future<Output = Result<R, Box<dyn Error + Send + Sync>>>,
     {
-        let mut spinner = Spinner::new(Spinners::Dots12, start_message.into());
+        let mut spinner =
+            Spinner::with_stream(Spinners::Dots12, start_message.into(), Stream::Stderr);
         let result = future.await;
         if result.is_ok() {
-            print!("\r");
+            eprint!("\r");
             spinner.stop_with_message(completion_message.into());
-            println!();
+            eprintln!();
         } else {
-            println!();
+            eprintln!();
         }
         result
     }
@@ -75,10 +95,10 @@ impl NetworkService for NetworkWorker {
         &mut self,
         project: &Project,
     ) -> Result<ResponseWithEtag<RemoteConfig>, Box<dyn Error + Send + Sync>> {
-        NetworkWorker::perform_with_spinner(
+        let response = NetworkWorker::perform_with_spinner(
             "Downloading remote config...",
             "Downloading completed successfully",
-            async move {
+            async {
                 let access_token = self.authenticator.get_access_token().await?;
                 let response = self
                     .client
@@ -103,7 +123,12 @@ impl NetworkService for NetworkWorker {
                 })
             },
         )
-        .await
+        .await?;
+        self.fetched_configs.insert(
+            project.project_number().to_string(),
+            (response.etag.clone(), response.data.clone()),
+        );
+        Ok(response)
     }
 
     async fn update_remote_config(
@@ -111,15 +136,68 @@ impl NetworkService for NetworkWorker {
         project: &Project,
         config: RemoteConfig,
         etag: String,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.publish(project, config, etag, true).await
+    }
+
+    async fn revert_remote_config(
+        &mut self,
+        project: &Project,
+        config: RemoteConfig,
+        etag: String,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.publish(project, config, etag, false).await
+    }
+}
+
+impl NetworkWorker {
+    fn fetched_config(&self, project: &Project, etag: &str) -> Option<&RemoteConfig> {
+        self.fetched_configs
+            .get(project.project_number())
+            .filter(|(fetched_etag, _)| fetched_etag == etag)
+            .map(|(_, config)| config)
+    }
+
+    /// Uploads config, records the change to the journal and optionally saves its snapshot.
+    async fn publish(
+        &mut self,
+        project: &Project,
+        config: RemoteConfig,
+        etag: String,
+        is_snapshot_saved: bool,
     ) -> Result<(), Box<dyn Error + Send + Sync>> {
         debug!("Remote config to upload: {:#?}", &config);
-        NetworkWorker::perform_with_spinner(
+        if self.fetched_config(project, &etag).is_none() {
+            debug!("Config before the change was not fetched, downloading it");
+            self.get_remote_config(project).await?;
+        }
+        let previous_config = self.fetched_config(project, &etag);
+        let changes = previous_config.map(|previous| previous.diff(&config));
+        let snapshot_path = previous_config
+            .filter(|_| is_snapshot_saved)
+            .and_then(|previous| {
+                let snapshot = Snapshot {
+                    timestamp: Utc::now(),
+                    project: project.name.clone(),
+                    previous_etag: etag.clone(),
+                    before: previous.clone(),
+                    after: config.clone(),
+                };
+                self.snapshot_store
+                    .save(project.project_number(), &snapshot)
+                    .map_err(|error| warn!("Snapshot was not saved: {}", error))
+                    .ok()
+            });
+        let previous_etag = etag.clone();
+        let uploaded_config = config.clone();
+        let new_etag = NetworkWorker::perform_with_spinner(
             "Uploading remote config...",
             "Uploading completed successfully",
-            async move {
+            async {
                 let access_token = self.authenticator.get_access_token().await?;
                 let bytes = serde_json::to_string(&config)?.into_bytes();
-                self.client
+                let response = self
+                    .client
                     .put(project.url())
                     .header(AUTHORIZATION, format!("Bearer {}", access_token.as_str()))
                     .header(ACCEPT_ENCODING, "gzip, deflate, br")
@@ -128,9 +206,50 @@ impl NetworkService for NetworkWorker {
                     .send()
                     .await?
                     .error_for_status()?;
-                Ok(())
+                let etag = response
+                    .headers()
+                    .get(ETAG)
+                    .and_then(|etag| etag.to_str().ok())
+                    .map(String::from);
+                Ok(etag)
             },
         )
-        .await
+        .await;
+        let new_etag = match new_etag {
+            Ok(new_etag) => new_etag,
+            Err(error) => {
+                if let Some(path) = snapshot_path {
+                    let _ = self.snapshot_store.remove(&path);
+                }
+                return Err(error);
+            }
+        };
+
+        match changes {
+            Some(changes) => {
+                let entry = JournalEntry::new(
+                    project.name.clone(),
+                    project.project_number().to_string(),
+                    previous_etag,
+                    new_etag.clone(),
+                    changes,
+                );
+                if let Err(error) = self.journal.append(&entry) {
+                    warn!("Change was not recorded to the journal: {}", error);
+                }
+            }
+            None => warn!("Change was not recorded to the journal: previous config is unknown"),
+        }
+        match new_etag {
+            Some(new_etag) => {
+                let key = project.project_number().to_string();
+                self.fetched_configs
+                    .insert(key, (new_etag, uploaded_config));
+            }
+            None => {
+                self.fetched_configs.remove(project.project_number());
+            }
+        }
+        Ok(())
     }
 }
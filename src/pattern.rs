@@ -0,0 +1,84 @@
+use crate::error::Error;
+use regex::Regex;
+use std::str::FromStr;
+
+/// Parameter name pattern.
+/// Pattern wrapped in slashes (`/^ios_.*/`) is treated as a regular expression,
+/// any other value is treated as a glob (`ios_*`).
+#[derive(Debug, Clone)]
+pub struct NamePattern {
+    regex: Regex,
+}
+
+impl NamePattern {
+    pub fn is_match(&self, name: &str) -> bool {
+        self.regex.is_match(name)
+    }
+}
+
+impl FromStr for NamePattern {
+    type Err = Error;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        let is_regex = pattern.len() > 1 && pattern.starts_with('/') && pattern.ends_with('/');
+        let regex = if is_regex {
+            Regex::new(&pattern[1..pattern.len() - 1])
+        } else {
+            Regex::new(&glob_to_regex(pattern))
+        };
+        regex.map(|regex| Self { regex }).map_err(|error| Error {
+            message: format!("Invalid pattern {}: {}", pattern, error),
+        })
+    }
+}
+
+fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::with_capacity(glob.len() + 2);
+    regex.push('^');
+    let mut is_in_class = false;
+    for char in glob.chars() {
+        match char {
+            '*' if !is_in_class => regex.push_str(".*"),
+            '?' if !is_in_class => regex.push('.'),
+            '[' if !is_in_class => {
+                is_in_class = true;
+                regex.push('[');
+            }
+            '!' if is_in_class && regex.ends_with('[') => regex.push('^'),
+            ']' if is_in_class => {
+                is_in_class = false;
+                regex.push(']');
+            }
+            '-' if is_in_class => regex.push('-'),
+            _ => regex.push_str(&regex::escape(&char.to_string())),
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_pattern() {
+        let pattern = NamePattern::from_str("ios_*_enabled").unwrap();
+        assert!(pattern.is_match("ios_camera_enabled"));
+        assert!(!pattern.is_match("android_camera_enabled"));
+        assert!(!pattern.is_match("ios_camera_enabled_v2"));
+
+        let pattern = NamePattern::from_str("flag_[!a]?").unwrap();
+        assert!(pattern.is_match("flag_b1"));
+        assert!(!pattern.is_match("flag_a1"));
+    }
+
+    #[test]
+    fn regex_pattern() {
+        let pattern = NamePattern::from_str("/^(ios|android)_/").unwrap();
+        assert!(pattern.is_match("ios_camera"));
+        assert!(pattern.is_match("android_camera"));
+        assert!(!pattern.is_match("web_camera"));
+        assert!(NamePattern::from_str("/(/").is_err());
+    }
+}
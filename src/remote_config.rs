@@ -1,3 +1,4 @@
+use clap::ArgEnum;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
@@ -59,10 +60,11 @@ pub struct Parameter {
     pub value_type: ParameterValueType,
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy, ArgEnum)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum ParameterValueType {
     #[serde(rename = "PARAMETER_VALUE_TYPE_UNSPECIFIED")]
+    #[clap(skip)]
     Unspecified,
     Boolean,
     String,
@@ -94,6 +96,21 @@ impl RemoteConfig {
             })
         }
     }
+
+    pub fn find_parameter(&self, name: &str) -> Option<(Option<&str>, &Parameter)> {
+        match self.parameters.get(name) {
+            Some(parameter) => Some((None, parameter)),
+            None => self
+                .parameter_groups
+                .iter()
+                .find_map(|(group_name, group)| {
+                    group
+                        .parameters
+                        .get(name)
+                        .map(|parameter| (Some(group_name.as_str()), parameter))
+                }),
+        }
+    }
 }
 
 impl Display for Condition {
@@ -0,0 +1,554 @@
+use crate::commands::ConfigFile;
+use crate::error::{Error, Result};
+use crate::remote_config::{Parameter, ParameterValue, ParameterValueType};
+use directories_next::ProjectDirs;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+use tracing::{debug, warn};
+
+const SCHEMA_DIR_NAME: &str = "schemas";
+/// Validation keywords implemented by `validate` and annotations which don't affect validation
+const SUPPORTED_KEYWORDS: &[&str] = &[
+    "type",
+    "enum",
+    "const",
+    "properties",
+    "required",
+    "additionalProperties",
+    "items",
+    "minItems",
+    "maxItems",
+    "uniqueItems",
+    "minimum",
+    "maximum",
+    "exclusiveMinimum",
+    "exclusiveMaximum",
+    "multipleOf",
+    "minLength",
+    "maxLength",
+    "pattern",
+    "allOf",
+    "anyOf",
+    "oneOf",
+    "not",
+    "$schema",
+    "$id",
+    "$comment",
+    "title",
+    "description",
+    "default",
+    "examples",
+];
+
+/// JSON Schemas of parameter values by parameter name.
+/// Schemas are taken from `schemas` of the config file and from `<name>.json` files of the schema directory.
+#[derive(Debug, Default, Clone)]
+pub struct Schemas {
+    schemas: HashMap<String, Value>,
+    /// Errors of schemas which failed to load by parameter name, values of these parameters are rejected
+    invalid_schemas: HashMap<String, String>,
+}
+
+/// Parameter value which does not match the schema.
+#[derive(Debug, PartialEq)]
+pub struct SchemaViolation {
+    pub value: String,
+    pub path: String,
+    pub message: String,
+}
+
+impl Display for SchemaViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}: {}", self.value, self.path, self.message)
+    }
+}
+
+impl Schemas {
+    pub fn load(app_name: &str) -> Result<Self> {
+        let config = ConfigFile::new(app_name.to_string()).load()?;
+        let mut schemas = Self::default();
+        for (name, schema) in config.schemas {
+            schemas.insert(name, Ok(schema), "config file");
+        }
+        let directory = match config.schema_dir {
+            Some(directory) => directory,
+            None => ProjectDirs::from("com", "", app_name)
+                .map(|directories| directories.config_dir().join(SCHEMA_DIR_NAME))
+                .ok_or_else(|| Error::new("Could not determine project directories path"))?,
+        };
+        if !directory.is_dir() {
+            return Ok(schemas);
+        }
+        for entry in fs::read_dir(&directory)? {
+            let path = entry?.path();
+            let name = match path.file_stem().and_then(|name| name.to_str()) {
+                Some(name) if path.extension().map(|ext| ext == "json").unwrap_or(false) => name,
+                _ => continue,
+            };
+            let schema = Self::load_schema(&path);
+            schemas.insert(name.to_string(), schema, &format!("{:?}", path));
+        }
+        Ok(schemas)
+    }
+
+    fn load_schema(path: &PathBuf) -> Result<Value> {
+        let content = fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error::new("schema is not valid JSON")
+        })
+    }
+
+    /// Keeps schema if it's supported, otherwise reports the error and rejects values of the parameter.
+    fn insert(&mut self, name: String, schema: Result<Value>, source: &str) {
+        let error = match schema {
+            Ok(schema) => match find_unsupported_keyword(&schema, "") {
+                None => {
+                    self.invalid_schemas.remove(&name);
+                    self.schemas.insert(name, schema);
+                    return;
+                }
+                Some(path) => format!("schema uses unsupported keyword at {}", path),
+            },
+            Err(error) => error.message,
+        };
+        warn!("Skipping {} schema from {}: {}", name, source, error);
+        self.schemas.remove(&name);
+        self.invalid_schemas.insert(name, error);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.schemas.is_empty() && self.invalid_schemas.is_empty()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.schemas.contains_key(name) || self.invalid_schemas.contains_key(name)
+    }
+
+    /// Validates default and conditional values of the parameter, values using in-app default are skipped.
+    pub fn validate(&self, name: &str, parameter: &Parameter) -> Vec<SchemaViolation> {
+        if let Some(error) = self.invalid_schemas.get(name) {
+            return vec![SchemaViolation {
+                value: "schema".to_string(),
+                path: "/".to_string(),
+                message: error.clone(),
+            }];
+        }
+        let schema = match self.schemas.get(name) {
+            None => return Vec::new(),
+            Some(schema) => schema,
+        };
+        let mut conditional_values = parameter.conditional_values.iter().collect::<Vec<_>>();
+        conditional_values.sort_by_key(|(condition, _)| *condition);
+        let values = parameter
+            .default_value
+            .iter()
+            .map(|value| ("default value".to_string(), value))
+            .chain(
+                conditional_values
+                    .into_iter()
+                    .map(|(condition, value)| (format!("{} conditional value", condition), value)),
+            );
+        let mut violations = Vec::new();
+        for (label, value) in values {
+            let value = match value {
+                ParameterValue::Value(value) => value,
+                ParameterValue::UseInAppDefault(_) => continue,
+            };
+            let value = match json_value(value, &parameter.value_type) {
+                Some(value) => value,
+                None => {
+                    violations.push(SchemaViolation {
+                        value: label,
+                        path: "/".to_string(),
+                        message: "value is not valid JSON".to_string(),
+                    });
+                    continue;
+                }
+            };
+            let mut errors = Vec::new();
+            validate(schema, &value, "", &mut errors);
+            violations.extend(errors.into_iter().map(|(path, message)| SchemaViolation {
+                value: label.clone(),
+                path: if path.is_empty() {
+                    "/".to_string()
+                } else {
+                    path
+                },
+                message,
+            }));
+        }
+        violations
+    }
+
+    /// Returns error listing failing paths if parameter values do not match the schema.
+    pub fn check(&self, name: &str, parameter: &Parameter) -> Result<()> {
+        if let Some(error) = self.invalid_schemas.get(name) {
+            return Err(Error {
+                message: format!("Schema of {} parameter can't be used: {}", name, error),
+            });
+        }
+        let violations = self.validate(name, parameter);
+        if violations.is_empty() {
+            return Ok(());
+        }
+        let violations = violations
+            .iter()
+            .map(|violation| format!("  {}", violation))
+            .collect::<Vec<_>>();
+        Err(Error {
+            message: format!(
+                "Parameter {} does not match its schema:\n{}",
+                name,
+                violations.join("\n")
+            ),
+        })
+    }
+}
+
+fn json_value(value: &str, value_type: &ParameterValueType) -> Option<Value> {
+    match value_type {
+        ParameterValueType::Json => serde_json::from_str(value).ok(),
+        ParameterValueType::Boolean => value.parse::<bool>().ok().map(Value::Bool),
+        ParameterValueType::Number => serde_json::from_str::<serde_json::Number>(value)
+            .ok()
+            .map(Value::Number),
+        ParameterValueType::String | ParameterValueType::Unspecified => {
+            Some(Value::String(value.to_string()))
+        }
+    }
+}
+
+/// Validates `value` against supported subset of JSON Schema keywords,
+/// pushes JSON pointer of every failing value with the error.
+fn validate(schema: &Value, value: &Value, path: &str, errors: &mut Vec<(String, String)>) {
+    let schema = match schema {
+        Value::Bool(true) => return,
+        Value::Bool(false) => {
+            errors.push((path.to_string(), "no value is allowed".to_string()));
+            return;
+        }
+        Value::Object(schema) => schema,
+        _ => return,
+    };
+    let mut error = |message: String| errors.push((path.to_string(), message));
+
+    if let Some(expected) = schema.get("type") {
+        let types = match expected {
+            Value::String(value_type) => vec![value_type.as_str()],
+            Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !types.is_empty() && !types.iter().any(|value_type| has_type(value, value_type)) {
+            error(format!("expected {}", types.join(" or ")));
+            return;
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            error(format!("expected {}", expected));
+        }
+    }
+    if let Some(Value::Array(allowed)) = schema.get("enum") {
+        if !allowed.contains(value) {
+            let allowed = allowed.iter().map(Value::to_string).collect::<Vec<_>>();
+            error(format!("expected one of {}", allowed.join(", ")));
+        }
+    }
+    if let Value::Number(number) = value {
+        let number = number.as_f64().unwrap_or_default();
+        let bound = |keyword: &str| schema.get(keyword).and_then(Value::as_f64);
+        if let Some(minimum) = bound("minimum").filter(|minimum| number < *minimum) {
+            error(format!("must be >= {}", minimum));
+        }
+        if let Some(maximum) = bound("maximum").filter(|maximum| number > *maximum) {
+            error(format!("must be <= {}", maximum));
+        }
+        if let Some(minimum) = bound("exclusiveMinimum").filter(|minimum| number <= *minimum) {
+            error(format!("must be > {}", minimum));
+        }
+        if let Some(maximum) = bound("exclusiveMaximum").filter(|maximum| number >= *maximum) {
+            error(format!("must be < {}", maximum));
+        }
+        if let Some(divisor) = bound("multipleOf").filter(|divisor| *divisor != 0.0) {
+            if (number / divisor).fract() != 0.0 {
+                error(format!("must be a multiple of {}", divisor));
+            }
+        }
+    }
+    if let Value::String(string) = value {
+        let length = string.chars().count() as u64;
+        if let Some(min_length) = schema.get("minLength").and_then(Value::as_u64) {
+            if length < min_length {
+                error(format!("must be at least {} characters long", min_length));
+            }
+        }
+        if let Some(max_length) = schema.get("maxLength").and_then(Value::as_u64) {
+            if length > max_length {
+                error(format!("must be at most {} characters long", max_length));
+            }
+        }
+        if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+            match Regex::new(pattern) {
+                Ok(regex) if !regex.is_match(string) => {
+                    error(format!("must match {} pattern", pattern))
+                }
+                Ok(_) => {}
+                Err(_) => error(format!("schema pattern {} is invalid", pattern)),
+            }
+        }
+    }
+    if let Value::Array(items) = value {
+        let count = items.len() as u64;
+        if let Some(min_items) = schema.get("minItems").and_then(Value::as_u64) {
+            if count < min_items {
+                error(format!("must contain at least {} items", min_items));
+            }
+        }
+        if let Some(max_items) = schema.get("maxItems").and_then(Value::as_u64) {
+            if count > max_items {
+                error(format!("must contain at most {} items", max_items));
+            }
+        }
+        if schema.get("uniqueItems") == Some(&Value::Bool(true)) {
+            let has_duplicates = items
+                .iter()
+                .enumerate()
+                .any(|(index, item)| items[..index].contains(item));
+            if has_duplicates {
+                error("items must be unique".to_string());
+            }
+        }
+        match schema.get("items") {
+            Some(Value::Array(schemas)) => {
+                for (index, (item, schema)) in items.iter().zip(schemas).enumerate() {
+                    validate(schema, item, &format!("{}/{}", path, index), errors);
+                }
+            }
+            Some(schema) => {
+                for (index, item) in items.iter().enumerate() {
+                    validate(schema, item, &format!("{}/{}", path, index), errors);
+                }
+            }
+            None => {}
+        }
+    }
+    if let Value::Object(object) = value {
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for key in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(key) {
+                    errors.push((path.to_string(), format!("{} property is required", key)));
+                }
+            }
+        }
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (key, item) in object {
+            let item_path = format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+            match (
+                properties.and_then(|properties| properties.get(key)),
+                schema.get("additionalProperties"),
+            ) {
+                (Some(schema), _) => validate(schema, item, &item_path, errors),
+                (None, Some(Value::Bool(false))) => {
+                    errors.push((item_path, "property is not allowed".to_string()))
+                }
+                (None, Some(schema)) => validate(schema, item, &item_path, errors),
+                (None, None) => {}
+            }
+        }
+    }
+
+    for schema in schema
+        .get("allOf")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        validate(schema, value, path, errors);
+    }
+    let matching_count = |keyword: &str| {
+        schema
+            .get(keyword)
+            .and_then(Value::as_array)
+            .map(|schemas| {
+                schemas
+                    .iter()
+                    .filter(|schema| {
+                        let mut errors = Vec::new();
+                        validate(schema, value, path, &mut errors);
+                        errors.is_empty()
+                    })
+                    .count()
+            })
+    };
+    if matching_count("anyOf") == Some(0) {
+        errors.push((
+            path.to_string(),
+            "must match any of anyOf schemas".to_string(),
+        ));
+    }
+    if matching_count("oneOf")
+        .map(|count| count != 1)
+        .unwrap_or(false)
+    {
+        errors.push((
+            path.to_string(),
+            "must match exactly one of oneOf schemas".to_string(),
+        ));
+    }
+    if let Some(schema) = schema.get("not") {
+        let mut not_errors = Vec::new();
+        validate(schema, value, path, &mut not_errors);
+        if not_errors.is_empty() {
+            errors.push((path.to_string(), "must not match not schema".to_string()));
+        }
+    }
+}
+
+/// Returns JSON pointer of the first keyword which `validate` would ignore.
+fn find_unsupported_keyword(schema: &Value, path: &str) -> Option<String> {
+    let schema = match schema {
+        Value::Object(schema) => schema,
+        _ => return None,
+    };
+    let keyword_path =
+        |keyword: &str| format!("{}/{}", path, keyword.replace('~', "~0").replace('/', "~1"));
+    if let Some(keyword) = schema
+        .keys()
+        .find(|keyword| !SUPPORTED_KEYWORDS.contains(&keyword.as_str()))
+    {
+        return Some(keyword_path(keyword));
+    }
+    let mut subschemas = Vec::new();
+    for keyword in ["additionalProperties", "items", "not"] {
+        if let Some(subschema @ (Value::Object(_) | Value::Bool(_))) = schema.get(keyword) {
+            subschemas.push((keyword_path(keyword), subschema));
+        }
+    }
+    for keyword in ["items", "allOf", "anyOf", "oneOf"] {
+        if let Some(Value::Array(items)) = schema.get(keyword) {
+            for (index, subschema) in items.iter().enumerate() {
+                subschemas.push((format!("{}/{}", keyword_path(keyword), index), subschema));
+            }
+        }
+    }
+    if let Some(Value::Object(properties)) = schema.get("properties") {
+        for (name, subschema) in properties {
+            let properties_path = keyword_path("properties");
+            let name = name.replace('~', "~0").replace('/', "~1");
+            subschemas.push((format!("{}/{}", properties_path, name), subschema));
+        }
+    }
+    subschemas
+        .into_iter()
+        .find_map(|(path, subschema)| find_unsupported_keyword(subschema, &path))
+}
+
+fn has_type(value: &Value, value_type: &str) -> bool {
+    match value_type {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value
+            .as_f64()
+            .map(|number| number.fract() == 0.0)
+            .unwrap_or(false),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn violations_contain_failing_paths() {
+        let schema = json!({
+            "type": "object",
+            "required": ["items"],
+            "properties": {
+                "items": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "required": ["id"],
+                        "properties": { "id": { "type": "integer", "minimum": 1 } },
+                        "additionalProperties": false
+                    }
+                }
+            }
+        });
+        let schemas = Schemas {
+            schemas: HashMap::from([("checkout".to_string(), schema)]),
+            ..Default::default()
+        };
+        let mut parameter = Parameter::stub();
+        parameter.value_type = ParameterValueType::Json;
+        parameter.default_value = Some(ParameterValue::Value(
+            r#"{"items": [{"id": 1}, {"id": 0, "name": "x"}, {}]}"#.to_string(),
+        ));
+        parameter.conditional_values = HashMap::from([(
+            "iOS".to_string(),
+            ParameterValue::Value(r#"{"items": [{"id": 2}]}"#.to_string()),
+        )]);
+
+        let violations = schemas
+            .validate("checkout", &parameter)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            violations,
+            vec![
+                "default value at /items/1/id: must be >= 1",
+                "default value at /items/1/name: property is not allowed",
+                "default value at /items/2: id property is required",
+            ]
+        );
+        assert!(schemas.validate("other", &parameter).is_empty());
+    }
+
+    #[test]
+    fn unsupported_schemas_reject_values() {
+        let mut schemas = Schemas::default();
+        let schema = json!({
+            "type": "object",
+            "properties": { "items": { "type": "array", "items": { "$ref": "#/$defs/item" } } }
+        });
+        schemas.insert("checkout".to_string(), Ok(schema), "config file");
+        schemas.insert(
+            "banner".to_string(),
+            Err(Error::new("schema is not valid JSON")),
+            "banner.json",
+        );
+        schemas.insert(
+            "timeout".to_string(),
+            Ok(json!({ "type": "integer", "title": "Timeout" })),
+            "timeout.json",
+        );
+        let parameter = Parameter::stub();
+
+        let checkout_error = schemas.check("checkout", &parameter).unwrap_err();
+        let banner_error = schemas.check("banner", &parameter).unwrap_err();
+
+        assert_eq!(
+            checkout_error.message,
+            "Schema of checkout parameter can't be used: schema uses unsupported keyword at /properties/items/items/$ref"
+        );
+        assert_eq!(
+            banner_error.message,
+            "Schema of banner parameter can't be used: schema is not valid JSON"
+        );
+        assert!(schemas.check("other", &parameter).is_ok());
+        assert!(schemas.contains("timeout"));
+    }
+}
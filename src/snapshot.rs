@@ -0,0 +1,142 @@
+use crate::error::{Error, Result};
+use crate::remote_config::RemoteConfig;
+use chrono::{DateTime, Utc};
+use directories_next::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::ErrorKind::NotFound;
+use std::path::{Path, PathBuf};
+use tracing::{debug, warn};
+
+const SNAPSHOTS_DIRECTORY_NAME: &str = "snapshots";
+/// Older snapshots are deleted when project has more of them
+const MAX_SNAPSHOTS_COUNT: usize = 50;
+
+/// Configs before and after single publish, stored per project in the data directory.
+pub struct SnapshotStore {
+    app_name: String,
+    /// Replaces the data directory when set
+    root_directory: Option<PathBuf>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Snapshot {
+    pub timestamp: DateTime<Utc>,
+    pub project: String,
+    pub previous_etag: String,
+    pub before: RemoteConfig,
+    pub after: RemoteConfig,
+}
+
+impl SnapshotStore {
+    pub fn new(app_name: String) -> Self {
+        Self {
+            app_name,
+            root_directory: None,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn with_root_directory(root_directory: PathBuf) -> Self {
+        Self {
+            app_name: String::new(),
+            root_directory: Some(root_directory),
+        }
+    }
+
+    /// Stores snapshot and returns its path.
+    pub fn save(&self, project_number: &str, snapshot: &Snapshot) -> Result<PathBuf> {
+        let directory = self.project_directory(project_number)?;
+        fs::create_dir_all(&directory).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error::new("Failed to create snapshots directory.")
+        })?;
+        let file_name = format!("{}.json", snapshot.timestamp.format("%Y%m%dT%H%M%S%.6fZ"));
+        let path = directory.join(file_name);
+        let content = serde_json::to_string(snapshot).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error::new("Failed to serialize snapshot.")
+        })?;
+        fs::write(&path, content).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error::new("Failed to write snapshot.")
+        })?;
+        self.prune(project_number)?;
+        Ok(path)
+    }
+
+    /// Returns snapshots of the project from the oldest to the latest.
+    pub fn list(&self, project_number: &str) -> Result<Vec<(PathBuf, Snapshot)>> {
+        let mut snapshots = self
+            .paths(project_number)?
+            .into_iter()
+            .filter_map(|path| {
+                let snapshot = fs::read_to_string(&path)
+                    .map_err(|error| error.to_string())
+                    .and_then(|content| {
+                        serde_json::from_str::<Snapshot>(&content)
+                            .map_err(|error| error.to_string())
+                    });
+                match snapshot {
+                    Ok(snapshot) => Some((path, snapshot)),
+                    Err(error) => {
+                        warn!("Skipping malformed snapshot {:?}: {}", path, error);
+                        None
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        snapshots.sort_by_key(|(_, snapshot)| snapshot.timestamp);
+        Ok(snapshots)
+    }
+
+    pub fn remove(&self, path: &Path) -> Result<()> {
+        fs::remove_file(path).map_err(|error| {
+            debug!("Error: {:?}", error);
+            Error {
+                message: format!("Failed to remove snapshot {:?}.", path),
+            }
+        })
+    }
+
+    fn prune(&self, project_number: &str) -> Result<()> {
+        let paths = self.paths(project_number)?;
+        if paths.len() > MAX_SNAPSHOTS_COUNT {
+            for path in paths.iter().take(paths.len() - MAX_SNAPSHOTS_COUNT) {
+                self.remove(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Snapshot paths sorted by name, which starts with the timestamp.
+    fn paths(&self, project_number: &str) -> Result<Vec<PathBuf>> {
+        let directory = self.project_directory(project_number)?;
+        let entries = match fs::read_dir(&directory) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == NotFound => return Ok(Vec::new()),
+            Err(error) => {
+                debug!("Error: {:?}", error);
+                return Err(Error::new("Failed to read snapshots directory."));
+            }
+        };
+        let mut paths = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().map(|ext| ext == "json").unwrap_or(false))
+            .collect::<Vec<_>>();
+        paths.sort();
+        Ok(paths)
+    }
+
+    fn project_directory(&self, project_number: &str) -> Result<PathBuf> {
+        if let Some(root_directory) = &self.root_directory {
+            return Ok(root_directory.join(project_number));
+        }
+        let directories = ProjectDirs::from("com", "", &self.app_name)
+            .ok_or_else(|| Error::new("Could not determine project directories path"))?;
+        Ok(directories
+            .data_dir()
+            .join(SNAPSHOTS_DIRECTORY_NAME)
+            .join(project_number))
+    }
+}